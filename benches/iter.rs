@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use elor::prelude::*;
+
+const LEN: usize = 100_000;
+
+fn either (data: &[u64]) -> Either<std::slice::Iter<'_, u64>, std::iter::Empty<&u64>> {
+    black_box(Left(data.iter()))
+}
+
+fn bench_fold (c: &mut Criterion) {
+    let data = (0..LEN as u64).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("fold");
+
+    group.bench_function("inner", |b| b.iter(|| black_box(data.iter()).fold(0, |acc, x| acc ^ x)));
+    group.bench_function("LRIter", |b| b.iter(|| either(&data).into_same_iter().fold(0, |acc, x| acc ^ x)));
+    group.bench_function("Either", |b| b.iter(|| Iterator::fold(either(&data), 0, |acc, x| acc ^ x.fold(|x| *x, |x| *x))));
+    group.finish();
+}
+
+fn bench_nth (c: &mut Criterion) {
+    let data = (0..LEN as u64).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("nth");
+
+    group.bench_function("inner", |b| b.iter(|| black_box(data.iter()).nth(LEN - 1)));
+    group.bench_function("LRIter", |b| b.iter(|| either(&data).into_same_iter().nth(LEN - 1)));
+    group.bench_function("Either", |b| b.iter(|| either(&data).nth(LEN - 1)));
+    group.finish();
+}
+
+fn bench_nth_back (c: &mut Criterion) {
+    let data = (0..LEN as u64).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("nth_back");
+
+    group.bench_function("inner", |b| b.iter(|| black_box(data.iter()).nth_back(LEN - 1)));
+    group.bench_function("LRIter", |b| b.iter(|| either(&data).into_same_iter().nth_back(LEN - 1)));
+    group.finish();
+}
+
+fn bench_count (c: &mut Criterion) {
+    let data = (0..LEN as u64).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("count");
+
+    group.bench_function("inner", |b| b.iter(|| black_box(data.iter()).count()));
+    group.bench_function("LRIter", |b| b.iter(|| either(&data).into_same_iter().count()));
+    group.bench_function("Either", |b| b.iter(|| either(&data).count()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_fold, bench_nth, bench_nth_back, bench_count);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, visit_mut::VisitMut, Block, Expr, ExprReturn, ItemFn, Stmt};
+
+const VARIANTS: [&str; 8] = ["First", "Second", "Third", "Fourth", "Fifth", "Sixth", "Seventh", "Eighth"];
+
+/// How the returned values are unified into a single type
+#[derive(Clone, Copy)]
+enum Mode {
+    /// `Either`, `Either3`, ..., `Either8`
+    Either,
+    /// Right-nested `LRIter`s, for iterators with the same item
+    Iterator,
+    /// Right-nested `LRFuture`s, for futures with the same output
+    Future,
+}
+
+pub fn expand (attr: TokenStream, mut item: ItemFn) -> TokenStream {
+    let mode = match attr.to_string().as_str() {
+        "" => Mode::Either,
+        "Iterator" => Mode::Iterator,
+        "Future" => Mode::Future,
+        _ => {
+            let error = syn::Error::new(attr.span(), "expected no arguments, `Iterator` or `Future`").to_compile_error();
+            return quote! { #item #error };
+        }
+    };
+
+    let mut count = 0;
+    for_each_leaf(&mut item.block, &mut |_| count += 1);
+
+    let mut idx = 0;
+    for_each_leaf(&mut item.block, &mut |expr| {
+        *expr = wrap(expr, mode, idx, count);
+        idx += 1;
+    });
+
+    quote! { #item }
+}
+
+/// Calls `f` on every expression that can become the function's return value, in a stable order:
+/// first the values of `return` expressions, then the branches of the tail expression.
+fn for_each_leaf<F: FnMut(&mut Expr)> (block: &mut Block, f: &mut F) {
+    Returns(f).visit_block_mut(block);
+    block_tail(block, f);
+}
+
+fn block_tail<F: FnMut(&mut Expr)> (block: &mut Block, f: &mut F) {
+    if let Some(Stmt::Expr(expr)) = block.stmts.last_mut() {
+        tail(expr, f)
+    }
+}
+
+fn tail<F: FnMut(&mut Expr)> (expr: &mut Expr, f: &mut F) {
+    match expr {
+        Expr::If(x) => {
+            // an `if` without an `else` evaluates to `()`, so there's nothing to wrap
+            if let Some((_, otherwise)) = &mut x.else_branch {
+                block_tail(&mut x.then_branch, f);
+                tail(otherwise, f);
+            }
+        },
+
+        Expr::Match(x) => x.arms.iter_mut().for_each(|arm| tail(&mut arm.body, f)),
+        Expr::Block(x) if x.label.is_none() => block_tail(&mut x.block, f),
+        Expr::Unsafe(x) => block_tail(&mut x.block, f),
+        Expr::Paren(x) => tail(&mut x.expr, f),
+        x if diverges(x) => {},
+        x => f(x)
+    }
+}
+
+/// Returns `true` for expressions that never evaluate to a value, and therefore never have to be wrapped
+fn diverges (expr: &Expr) -> bool {
+    match expr {
+        Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) => true,
+        Expr::Macro(x) => x.mac.path.segments.last().is_some_and(|x| {
+            matches!(x.ident.to_string().as_str(), "panic" | "unreachable" | "todo" | "unimplemented")
+        }),
+        _ => false
+    }
+}
+
+fn wrap (expr: &Expr, mode: Mode, idx: usize, count: usize) -> Expr {
+    let same = match mode {
+        Mode::Either => None,
+        Mode::Iterator => Some(quote! { ::elor::iter::LRIter::new }),
+        Mode::Future => Some(quote! { ::elor::LRFuture::new }),
+    };
+
+    match (count, same) {
+        (0 | 1, _) => expr.clone(),
+        (2, None) if idx == 0 => syn::parse_quote! { ::elor::Either::Left(#expr) },
+        (2, None) => syn::parse_quote! { ::elor::Either::Right(#expr) },
+        (3..=8, None) => {
+            let name = quote::format_ident!("Either{count}");
+            let variant = quote::format_ident!("{}", VARIANTS[idx]);
+            syn::parse_quote! { ::elor::#name::#variant(#expr) }
+        },
+
+        (_, same) => {
+            // right-nested `Either`s, optionally unified at every level
+            let level = |x: TokenStream| match &same {
+                Some(same) => quote! { #same(#x) },
+                None => x
+            };
+
+            let mut result = match idx == count - 1 {
+                true => quote! { #expr },
+                false => level(quote! { ::elor::Either::Left(#expr) })
+            };
+
+            for _ in 0..idx {
+                result = level(quote! { ::elor::Either::Right(#result) });
+            }
+
+            syn::parse_quote! { #result }
+        }
+    }
+}
+
+/// Visits the values of every `return` expression that belongs to the function itself (not to closures, async blocks or nested items)
+struct Returns<'a, F> (&'a mut F);
+
+impl<F: FnMut(&mut Expr)> VisitMut for Returns<'_, F> {
+    fn visit_expr_return_mut (&mut self, node: &mut ExprReturn) {
+        if let Some(expr) = &mut node.expr {
+            tail(expr, self.0)
+        }
+    }
+
+    fn visit_expr_closure_mut (&mut self, _: &mut syn::ExprClosure) {}
+    fn visit_expr_async_mut (&mut self, _: &mut syn::ExprAsync) {}
+    fn visit_item_mut (&mut self, _: &mut syn::Item) {}
+}
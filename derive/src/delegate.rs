@@ -0,0 +1,206 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    spanned::Spanned, FnArg, GenericArgument, GenericParam, Ident, ItemTrait, Pat, PatIdent, PatType, PathArguments, ReturnType,
+    Signature, TraitItem, TraitItemMethod, TraitItemType, Type,
+};
+
+pub fn expand (attr: TokenStream, item: ItemTrait) -> TokenStream {
+    if !attr.is_empty() {
+        let error = syn::Error::new(attr.span(), "`delegate` doesn't take any arguments").to_compile_error();
+        return quote! { #item #error };
+    }
+
+    match delegate_impl(&item) {
+        Ok(imp) => quote! { #item #imp },
+        Err(e) => {
+            let error = e.to_compile_error();
+            quote! { #item #error }
+        }
+    }
+}
+
+fn delegate_impl (item: &ItemTrait) -> syn::Result<TokenStream> {
+    let name = &item.ident;
+    let left = Ident::new("__ElorLeft", Span::call_site());
+    let right = Ident::new("__ElorRight", Span::call_site());
+
+    let args = item.generics.params.iter().map(|param| match param {
+        GenericParam::Type(x) => x.ident.to_token_stream(),
+        GenericParam::Lifetime(x) => x.lifetime.to_token_stream(),
+        GenericParam::Const(x) => x.ident.to_token_stream(),
+    });
+    let trait_ref = quote! { #name<#(#args),*> };
+
+    let mut generics = item.generics.clone();
+    generics.params.push(syn::parse_quote!(#left: #trait_ref));
+    generics.params.push(syn::parse_quote!(#right: #trait_ref));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let assoc = item.items.iter().filter_map(|item| match item {
+        TraitItem::Type(x) => Some(x.ident.clone()),
+        _ => None,
+    }).collect::<Vec<_>>();
+
+    let mut items = Vec::with_capacity(item.items.len());
+    for trait_item in item.items.iter() {
+        match trait_item {
+            TraitItem::Type(x) => items.push(delegate_type(x, &trait_ref, &left, &right)),
+            TraitItem::Method(x) => items.push(delegate_method(x, &trait_ref, &left, &right, &assoc)?),
+            TraitItem::Const(x) => return Err(syn::Error::new(x.span(), "associated constants cannot be delegated through `Either`")),
+            other => return Err(syn::Error::new(other.span(), "unsupported trait item")),
+        }
+    }
+
+    let unsafety = &item.unsafety;
+    Ok(quote! {
+        #[automatically_derived]
+        #unsafety impl #impl_generics #trait_ref for ::elor::Either<#left, #right> #where_clause {
+            #(#items)*
+        }
+    })
+}
+
+fn delegate_type (item: &TraitItemType, trait_ref: &TokenStream, left: &Ident, right: &Ident) -> TokenStream {
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    quote! {
+        type #name #impl_generics = ::elor::Either<
+            <#left as #trait_ref>::#name #ty_generics,
+            <#right as #trait_ref>::#name #ty_generics
+        > #where_clause;
+    }
+}
+
+fn delegate_method (item: &TraitItemMethod, trait_ref: &TokenStream, left: &Ident, right: &Ident, assoc: &[Ident]) -> syn::Result<TokenStream> {
+    let mut sig = item.sig.clone();
+    let name = &sig.ident;
+
+    match sig.inputs.first() {
+        Some(FnArg::Receiver(_)) => {},
+        Some(FnArg::Typed(PatType { pat, .. })) if matches!(&**pat, Pat::Ident(PatIdent { ident, .. }) if ident == "self") => {
+            return Err(syn::Error::new(sig.inputs.span(), "typed `self` receivers cannot be delegated through `Either`"))
+        },
+        _ => return Err(syn::Error::new(sig.span(), "methods without a `self` receiver cannot be delegated through `Either`"))
+    }
+
+    let mut params = Vec::with_capacity(sig.inputs.len());
+    for (i, input) in sig.inputs.iter_mut().enumerate().skip(1) {
+        let FnArg::Typed(input) = input else { unreachable!() };
+        if mentions_self(input.ty.to_token_stream()) {
+            return Err(syn::Error::new(input.ty.span(), "arguments referencing `Self` cannot be delegated through `Either`"));
+        }
+
+        let ident = Ident::new(&format!("__elor_arg{i}"), input.pat.span());
+        *input.pat = syn::parse_quote!(#ident);
+        params.push(ident);
+    }
+
+    let turbofish = turbofish(&sig);
+    let call_left = quote! { <#left as #trait_ref>::#name #turbofish (__elor_this, #(#params),*) };
+    let call_right = quote! { <#right as #trait_ref>::#name #turbofish (__elor_this, #(#params),*) };
+
+    let (call_left, call_right) = match sig.asyncness {
+        Some(_) => (quote! { #call_left.await }, quote! { #call_right.await }),
+        None => (call_left, call_right),
+    };
+
+    let (call_left, call_right) = match sig.unsafety {
+        Some(_) => (quote! { unsafe { #call_left } }, quote! { unsafe { #call_right } }),
+        None => (call_left, call_right),
+    };
+
+    let (call_left, call_right) = match &sig.output {
+        ReturnType::Default => (call_left, call_right),
+        ReturnType::Type(_, ty) => (
+            wrap_output(ty, assoc, call_left, &quote! { ::elor::Either::Left })?,
+            wrap_output(ty, assoc, call_right, &quote! { ::elor::Either::Right })?
+        ),
+    };
+
+    let span = item.span();
+    Ok(quote_spanned! { span =>
+        #[inline]
+        #sig {
+            match self {
+                ::elor::Either::Left(__elor_this) => #call_left,
+                ::elor::Either::Right(__elor_this) => #call_right,
+            }
+        }
+    })
+}
+
+/// Wraps the result of a delegated call back into the side it came from, wherever `Self` or one of it's associated types appear.
+fn wrap_output (ty: &Type, assoc: &[Ident], expr: TokenStream, side: &TokenStream) -> syn::Result<TokenStream> {
+    if !mentions_self(ty.to_token_stream()) {
+        return Ok(expr);
+    }
+
+    if let Type::Path(path) = ty {
+        let segments = &path.path.segments;
+        let is_self = path.qself.is_none() && segments.first().is_some_and(|x| x.ident == "Self" && x.arguments.is_empty());
+
+        if is_self && (segments.len() == 1 || (segments.len() == 2 && assoc.contains(&segments[1].ident))) {
+            return Ok(quote! { #side(#expr) });
+        }
+
+        let last = segments.last().expect("paths have at least one segment");
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().filter_map(|x| match x {
+                GenericArgument::Type(x) => Some(x),
+                _ => None,
+            }).collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+
+        match (last.ident.to_string().as_str(), args.as_slice()) {
+            ("Option", [some]) => {
+                let some = wrap_output(some, assoc, quote! { __elor_value }, side)?;
+                return Ok(quote! { ::core::option::Option::map(#expr, |__elor_value| #some) });
+            },
+
+            ("Result", [ok, err]) => {
+                let ok = wrap_output(ok, assoc, quote! { __elor_value }, side)?;
+                let err = wrap_output(err, assoc, quote! { __elor_value }, side)?;
+                return Ok(quote! {
+                    ::core::result::Result::map_err(
+                        ::core::result::Result::map(#expr, |__elor_value| #ok),
+                        |__elor_value| #err
+                    )
+                });
+            },
+
+            _ => {}
+        }
+    }
+
+    Err(syn::Error::new(ty.span(), "only `Self`, it's associated types, `Option`s and `Result`s of them, and types not referencing `Self` can be returned by delegated methods"))
+}
+
+fn turbofish (sig: &Signature) -> TokenStream {
+    let has_impl_trait = sig.inputs.iter().any(|x| match x {
+        FnArg::Typed(x) => x.ty.to_token_stream().into_iter().any(|x| matches!(x, TokenTree::Ident(ident) if ident == "impl")),
+        FnArg::Receiver(_) => false,
+    });
+
+    let args = sig.generics.params.iter().filter_map(|x| match x {
+        GenericParam::Type(x) => Some(&x.ident),
+        GenericParam::Const(x) => Some(&x.ident),
+        GenericParam::Lifetime(_) => None,
+    }).collect::<Vec<_>>();
+
+    if has_impl_trait || args.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! { ::<#(#args),*> }
+}
+
+fn mentions_self (tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|x| match x {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
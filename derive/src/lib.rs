@@ -0,0 +1,94 @@
+//! Procedural macros for [`elor`](https://docs.rs/elor). Use them through `elor`'s `derive` feature instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, ItemFn, ItemTrait};
+
+mod delegate;
+mod auto_either;
+
+/// Implements the annotated trait for `Either<A, B>` whenever both `A` and `B` implement it,
+/// forwarding every method call to the value inside.
+///
+/// - Methods may take `self`, `&self` or `&mut self` as receivers
+/// - Associated types are implemented as `Either<A::Type, B::Type>`
+/// - Methods returning `Self` or one of the trait's associated types have their result wrapped on the same side it came from
+///
+/// ## Example ##
+/// ```rust
+/// use elor::prelude::*;
+///
+/// #[elor::delegate]
+/// trait Codec {
+///     type Output;
+///
+///     fn name (&self) -> &'static str;
+///     fn encode (&self, input: &str) -> Self::Output;
+/// }
+///
+/// struct Upper;
+/// struct Len;
+///
+/// impl Codec for Upper {
+///     type Output = String;
+///     fn name (&self) -> &'static str { "upper" }
+///     fn encode (&self, input: &str) -> String { input.to_uppercase() }
+/// }
+///
+/// impl Codec for Len {
+///     type Output = usize;
+///     fn name (&self) -> &'static str { "len" }
+///     fn encode (&self, input: &str) -> usize { input.len() }
+/// }
+///
+/// let codec : Either<Upper, Len> = Right(Len);
+/// assert_eq!(codec.name(), "len");
+/// assert_eq!(codec.encode("hello"), Right(5));
+/// ```
+#[proc_macro_attribute]
+pub fn delegate (attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemTrait);
+    delegate::expand(attr.into(), item).into()
+}
+
+/// Wraps every value the annotated function may return into a different variant of an `Either` type,
+/// allowing branches that return different types to share a single `impl Trait` return type.
+///
+/// The values of `return` expressions and the branches of the function's tail expression (through `if`/`else`, `match` and blocks)
+/// are wrapped in order. Branches that diverge (`return`, `break`, `continue`, `panic!`, `unreachable!`, `todo!` and `unimplemented!`) are left untouched.
+///
+/// - `#[auto_either]` wraps two values in [`Either`], three to eight in `Either3` to `Either8`, and more than eight in right-nested [`Either`]s.
+///   This works for traits the `Either` types pass through directly, like `Display`, `AsRef` or `Deref`.
+/// - `#[auto_either(Iterator)]` wraps the values in right-nested `LRIter`s, returning an iterator over the items of whichever iterator was returned.
+/// - `#[auto_either(Future)]` wraps the values in right-nested `LRFuture`s, returning a future that resolves to the output of whichever future was returned.
+///
+/// ## Example ##
+/// ```rust
+/// #[elor::auto_either(Iterator)]
+/// fn numbers (kind: u8) -> impl Iterator<Item = u32> {
+///     match kind {
+///         0 => core::iter::empty(),
+///         1 => core::iter::once(1),
+///         2 => vec![1, 2].into_iter(),
+///         _ => (1..=3).map(|x| x * 2)
+///     }
+/// }
+///
+/// #[elor::auto_either]
+/// fn name (kind: u8) -> impl core::fmt::Display {
+///     if kind == 0 {
+///         return "zero";
+///     }
+///
+///     kind
+/// }
+///
+/// assert_eq!(numbers(3).collect::<Vec<_>>(), vec![2, 4, 6]);
+/// assert_eq!(name(0).to_string(), "zero");
+/// ```
+///
+/// [`Either`]: https://docs.rs/elor/latest/elor/enum.Either.html
+#[proc_macro_attribute]
+pub fn auto_either (attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+    auto_either::expand(attr.into(), item).into()
+}
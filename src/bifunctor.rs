@@ -0,0 +1,209 @@
+use crate::{prelude::*, These, Validated};
+
+/// A type with two sides that can be mapped independently.
+///
+/// Implementations must satisfy the following laws:
+/// - ```x.bimap(|a| a, |b| b) == x```
+/// - ```x.bimap(|a| f2(f1(a)), |b| g2(g1(b))) == x.bimap(f1, g1).bimap(f2, g2)```
+///
+/// For ```Result``` and ```Validated```, the left side is the ```Ok```/```Valid``` value and the right side is the ```Err```/```Invalid``` value.
+/// ```LeftRight``` is covered by the implementation for ```Either```.
+pub trait Bifunctor<A, B>: Sized {
+    /// The same type, with ```X``` in place of ```A``` and ```Y``` in place of ```B```
+    type Target<X, Y>;
+
+    /// Maps both sides of the value
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> Self::Target<X, Y>;
+
+    /// Maps the left side of the value
+    #[inline(always)]
+    fn first<X, F: FnOnce(A) -> X> (self, f: F) -> Self::Target<X, B> {
+        self.bimap(f, |y| y)
+    }
+
+    /// Maps the right side of the value
+    #[inline(always)]
+    fn second<Y, G: FnOnce(B) -> Y> (self, g: G) -> Self::Target<A, Y> {
+        self.bimap(|x| x, g)
+    }
+}
+
+/// A type with two sides whose values can be folded into a single value.
+/// Left values are always folded before right values.
+///
+/// Implementations must satisfy the following law:
+/// - ```x.bimap(f, g).bifold(init, h, i) == x.bifold(init, |acc, a| h(acc, f(a)), |acc, b| i(acc, g(b)))```
+pub trait Bifoldable<A, B>: Sized {
+    /// Folds the values inside, starting from ```init```
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T;
+
+    /// Returns ```true``` if any of the values inside match their predicate
+    #[inline(always)]
+    fn biany<F: FnOnce(A) -> bool, G: FnOnce(B) -> bool> (self, f: F, g: G) -> bool {
+        self.bifold(false, |acc, x| acc || f(x), |acc, y| acc || g(y))
+    }
+
+    /// Returns ```true``` if all of the values inside match their predicate
+    #[inline(always)]
+    fn biall<F: FnOnce(A) -> bool, G: FnOnce(B) -> bool> (self, f: F, g: G) -> bool {
+        self.bifold(true, |acc, x| acc && f(x), |acc, y| acc && g(y))
+    }
+}
+
+/// A type with two sides that can be mapped with fallible functions, short-circuiting on the first failure.
+/// Left values are always mapped before right values.
+///
+/// Implementations must satisfy the following laws:
+/// - ```x.bitraverse(Ok::<_, E>, Ok::<_, E>) == Ok(x)```
+/// - ```x.bitraverse(|a| Ok::<_, E>(f(a)), |b| Ok::<_, E>(g(b))) == Ok(x.bimap(f, g))```
+pub trait Bitraversable<A, B>: Bifunctor<A, B> + Bifoldable<A, B> {
+    /// Maps both sides of the value, returning the first error found
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<Self::Target<X, Y>, E>;
+
+    /// Maps both sides of the value, returning ```None``` if any of the mappings does
+    #[inline(always)]
+    fn bitraverse_option<X, Y, F: FnOnce(A) -> Option<X>, G: FnOnce(B) -> Option<Y>> (self, f: F, g: G) -> Option<Self::Target<X, Y>> {
+        self.bitraverse(|x| f(x).ok_or(()), |y| g(y).ok_or(())).ok()
+    }
+}
+
+impl<A, B> Bifunctor<A, B> for Either<A, B> {
+    type Target<X, Y> = Either<X, Y>;
+
+    #[inline(always)]
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> Either<X, Y> {
+        self.map(f, g)
+    }
+}
+
+impl<A, B> Bifoldable<A, B> for Either<A, B> {
+    #[inline(always)]
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T {
+        match self {
+            Left(x) => f(init, x),
+            Right(y) => g(init, y)
+        }
+    }
+}
+
+impl<A, B> Bitraversable<A, B> for Either<A, B> {
+    #[inline(always)]
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<Either<X, Y>, E> {
+        match self {
+            Left(x) => f(x).map(Left),
+            Right(y) => g(y).map(Right)
+        }
+    }
+}
+
+impl<A, B> Bifunctor<A, B> for Result<A, B> {
+    type Target<X, Y> = Result<X, Y>;
+
+    #[inline(always)]
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> Result<X, Y> {
+        self.map(f).map_err(g)
+    }
+}
+
+impl<A, B> Bifoldable<A, B> for Result<A, B> {
+    #[inline(always)]
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T {
+        match self {
+            Ok(x) => f(init, x),
+            Err(y) => g(init, y)
+        }
+    }
+}
+
+impl<A, B> Bitraversable<A, B> for Result<A, B> {
+    #[inline(always)]
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<Result<X, Y>, E> {
+        match self {
+            Ok(x) => f(x).map(Ok),
+            Err(y) => g(y).map(Err)
+        }
+    }
+}
+
+impl<A, B> Bifunctor<A, B> for (A, B) {
+    type Target<X, Y> = (X, Y);
+
+    #[inline(always)]
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> (X, Y) {
+        (f(self.0), g(self.1))
+    }
+}
+
+impl<A, B> Bifoldable<A, B> for (A, B) {
+    #[inline(always)]
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T {
+        g(f(init, self.0), self.1)
+    }
+}
+
+impl<A, B> Bitraversable<A, B> for (A, B) {
+    #[inline(always)]
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<(X, Y), E> {
+        Ok((f(self.0)?, g(self.1)?))
+    }
+}
+
+impl<A, B> Bifunctor<A, B> for These<A, B> {
+    type Target<X, Y> = These<X, Y>;
+
+    #[inline(always)]
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> These<X, Y> {
+        self.map(f, g)
+    }
+}
+
+impl<A, B> Bifoldable<A, B> for These<A, B> {
+    #[inline(always)]
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T {
+        match self {
+            These::Left(x) => f(init, x),
+            These::Right(y) => g(init, y),
+            These::Both(x, y) => g(f(init, x), y)
+        }
+    }
+}
+
+impl<A, B> Bitraversable<A, B> for These<A, B> {
+    #[inline(always)]
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<These<X, Y>, E> {
+        match self {
+            These::Left(x) => f(x).map(These::Left),
+            These::Right(y) => g(y).map(These::Right),
+            These::Both(x, y) => Ok(These::Both(f(x)?, g(y)?))
+        }
+    }
+}
+
+impl<A, B> Bifunctor<A, B> for Validated<B, A> {
+    type Target<X, Y> = Validated<Y, X>;
+
+    #[inline(always)]
+    fn bimap<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> Validated<Y, X> {
+        self.map(f).map_errors(g)
+    }
+}
+
+impl<A, B> Bifoldable<A, B> for Validated<B, A> {
+    #[inline(always)]
+    fn bifold<T, F: FnOnce(T, A) -> T, G: FnOnce(T, B) -> T> (self, init: T, f: F, g: G) -> T {
+        match self {
+            Validated::Valid(x) => f(init, x),
+            Validated::Invalid(e) => g(init, e)
+        }
+    }
+}
+
+impl<A, B> Bitraversable<A, B> for Validated<B, A> {
+    #[inline(always)]
+    fn bitraverse<X, Y, E, F: FnOnce(A) -> Result<X, E>, G: FnOnce(B) -> Result<Y, E>> (self, f: F, g: G) -> Result<Validated<Y, X>, E> {
+        match self {
+            Validated::Valid(x) => f(x).map(Validated::Valid),
+            Validated::Invalid(e) => g(e).map(Validated::Invalid)
+        }
+    }
+}
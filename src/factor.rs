@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+impl<T,A,B> Either<(T,A),(T,B)> {
+    /// Extracts the first element shared by both sides, converting an ```Either<(T, A), (T, B)>``` into a ```(T, Either<A, B>)```
+    #[inline(always)]
+    pub fn factor_first (self) -> (T, Either<A,B>) {
+        match self {
+            Left((t, x)) => (t, Left(x)),
+            Right((t, x)) => (t, Right(x))
+        }
+    }
+
+    /// Inverse of ```factor_first```, converting a ```(T, Either<A, B>)``` into an ```Either<(T, A), (T, B)>```
+    #[inline(always)]
+    pub fn distribute ((t, value): (T, Either<A,B>)) -> Self {
+        match value {
+            Left(x) => Left((t, x)),
+            Right(x) => Right((t, x))
+        }
+    }
+}
+
+impl<A,B,T> Either<(A,T),(B,T)> {
+    /// Extracts the second element shared by both sides, converting an ```Either<(A, T), (B, T)>``` into a ```(Either<A, B>, T)```
+    #[inline(always)]
+    pub fn factor_second (self) -> (Either<A,B>, T) {
+        match self {
+            Left((x, t)) => (Left(x), t),
+            Right((x, t)) => (Right(x), t)
+        }
+    }
+
+    /// Inverse of ```factor_second```, converting an ```(Either<A, B>, T)``` into an ```Either<(A, T), (B, T)>```
+    #[inline(always)]
+    pub fn distribute_second ((value, t): (Either<A,B>, T)) -> Self {
+        match value {
+            Left(x) => Left((x, t)),
+            Right(x) => Right((x, t))
+        }
+    }
+}
+
+impl<A,B> Either<Option<A>,Option<B>> {
+    /// Extracts the ```None``` shared by both sides, converting an ```Either<Option<A>, Option<B>>``` into an ```Option<Either<A, B>>```
+    #[inline(always)]
+    pub fn factor_none (self) -> Option<Either<A,B>> {
+        self.flatten_option()
+    }
+
+    /// Inverse of ```factor_none```, converting ```None``` into ```Left(None)``` since its side is unknown
+    #[inline(always)]
+    pub fn distribute_none (value: Option<Either<A,B>>) -> Self {
+        Self::expand_option(value)
+    }
+}
+
+impl<A,B,E> Either<Result<A,E>,Result<B,E>> {
+    /// Extracts the error shared by both sides, converting an ```Either<Result<A, E>, Result<B, E>>``` into a ```Result<Either<A, B>, E>```
+    #[inline(always)]
+    pub fn factor_err (self) -> Result<Either<A,B>,E> {
+        self.transpose()
+    }
+
+    /// Inverse of ```factor_err```, converting ```Err(e)``` into ```Left(Err(e))``` since its side is unknown
+    #[inline(always)]
+    pub fn distribute_err (value: Result<Either<A,B>,E>) -> Self {
+        Self::expand_shared_result(value)
+    }
+}
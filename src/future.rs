@@ -1,11 +1,23 @@
-use futures::{Future, Stream};
+use core::{pin::Pin, task::{Context, Poll}};
+use futures::{Future, Stream, Sink, ready};
+use futures::io::{self as io, AsyncRead, AsyncWrite, AsyncBufRead, AsyncSeek, IoSlice, IoSliceMut, SeekFrom};
+use pin_project::pin_project;
 use crate::{EitherProj, prelude::*};
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        use std::collections::VecDeque;
+        use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+        use std::task::Waker;
+        use futures::task::{ArcWake, AtomicWaker};
+    }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-impl<A: Future, B: Future> Into<futures::future::Either<A,B>> for Either<A,B> {
+impl<A: Future, B: Future> From<Either<A,B>> for futures::future::Either<A,B> {
     #[inline(always)]
-    fn into(self) -> futures::future::Either<A,B> {
-        match self {
+    fn from(x: Either<A,B>) -> Self {
+        match x {
             Left(x) => futures::future::Either::Left(x),
             Right(x) => futures::future::Either::Right(x)
         }
@@ -47,4 +59,577 @@ impl<A: Stream, B: Stream> Stream for Either<A,B> {
             EitherProj::Right(x) => x.poll_next(cx).map(|x| x.map(Right)),
         }
     }
-}
\ No newline at end of file
+}
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<Item, A: Sink<Item>, B: Sink<Item>> Sink<Item> for Either<A,B> {
+    type Error = Either<A::Error, B::Error>;
+
+    #[inline(always)]
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_ready(cx).map_err(Left),
+            EitherProj::Right(x) => x.poll_ready(cx).map_err(Right),
+        }
+    }
+
+    #[inline(always)]
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        match self.project() {
+            EitherProj::Left(x) => x.start_send(item).map_err(Left),
+            EitherProj::Right(x) => x.start_send(item).map_err(Right),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_flush(cx).map_err(Left),
+            EitherProj::Right(x) => x.poll_flush(cx).map_err(Right),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_close(cx).map_err(Left),
+            EitherProj::Right(x) => x.poll_close(cx).map_err(Right),
+        }
+    }
+}
+
+/// Future that resolves to the output of whichever side of an [`Either`] is present, when both sides have the same [`Future::Output`]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[repr(transparent)]
+pub struct LRFuture<A, B> (#[pin] Either<A, B>);
+
+impl<T, A: Future<Output = T>, B: Future<Output = T>> Either<A, B> {
+    /// Turns an [`Either`] object with futures with the same [`Future::Output`] into a future
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    #[inline(always)]
+    pub fn into_same_future (self) -> LRFuture<A, B> {
+        LRFuture::new(self)
+    }
+}
+
+impl<T, A: Future<Output = T>, B: Future<Output = T>> LRFuture<A, B> {
+    #[inline(always)]
+    pub const fn new (fut: Either<A, B>) -> Self {
+        Self(fut)
+    }
+
+    #[inline(always)]
+    pub fn into_inner (self) -> Either<A, B> {
+        self.0
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<T, A: Future<Output = T>, B: Future<Output = T>> Future for LRFuture<A, B> {
+    type Output = T;
+
+    #[inline(always)]
+    fn poll(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+        match self.project().0.project() {
+            EitherProj::Left(x) => x.poll(cx),
+            EitherProj::Right(x) => x.poll(cx),
+        }
+    }
+}
+
+/// A stream with ```Either``` items
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub trait EitherStream<A, B>: Sized + Stream<Item = Either<A, B>> {
+    /// Returns two seperate streams that return the left and right values seperately.
+    /// Items meant for the other side are buffered, and the task waiting on the other side is woken up when they arrive.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    fn split_either (self) -> (SplitLeftStream<Self, A, B>, SplitRightStream<Self, A, B>) {
+        let wakers = Arc::new(SplitWakers { left: AtomicWaker::new(), right: AtomicWaker::new() });
+        let shared = Arc::new(SplitShared {
+            waker: futures::task::waker(wakers.clone()),
+            wakers,
+            state: Mutex::new(SplitState {
+                inner: Box::pin(self),
+                left: VecDeque::new(),
+                right: VecDeque::new(),
+                done: false
+            })
+        });
+
+        (SplitLeftStream { shared: shared.clone() }, SplitRightStream { shared })
+    }
+
+    /// Returns a stream over the left values, discarding the right ones
+    #[inline(always)]
+    fn lefts (self) -> Lefts<Self> {
+        Lefts { inner: self }
+    }
+
+    /// Returns a stream over the right values, discarding the left ones
+    #[inline(always)]
+    fn rights (self) -> Rights<Self> {
+        Rights { inner: self }
+    }
+
+    /// Returns a stream that maps the left values, leaving the right ones untouched
+    #[inline(always)]
+    fn map_left<T, F: FnMut(A) -> T> (self, f: F) -> MapLeft<Self, F> {
+        MapLeft { inner: self, f }
+    }
+
+    /// Returns a stream that maps the right values, leaving the left ones untouched
+    #[inline(always)]
+    fn map_right<T, F: FnMut(B) -> T> (self, f: F) -> MapRight<Self, F> {
+        MapRight { inner: self, f }
+    }
+
+    /// Returns a future that collects the left and right values into two seperate collections
+    #[inline(always)]
+    fn partition<CA: Default + Extend<A>, CB: Default + Extend<B>> (self) -> Partition<Self, CA, CB> {
+        Partition { inner: self, left: CA::default(), right: CB::default() }
+    }
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B> EitherStream<A, B> for S {}
+
+/// Stream over the left values of a stream of ```Either``` values
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[derive(Debug, Clone)]
+pub struct Lefts<S> {
+    #[pin]
+    inner: S
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B> Stream for Lefts<S> {
+    type Item = A;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Left(x)) => return Poll::Ready(Some(x)),
+                Some(Right(_)) => continue,
+                None => return Poll::Ready(None)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+/// Stream over the right values of a stream of ```Either``` values
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[derive(Debug, Clone)]
+pub struct Rights<S> {
+    #[pin]
+    inner: S
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B> Stream for Rights<S> {
+    type Item = B;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Right(x)) => return Poll::Ready(Some(x)),
+                Some(Left(_)) => continue,
+                None => return Poll::Ready(None)
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+/// Stream that maps the left values of a stream of ```Either``` values
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[derive(Debug, Clone)]
+pub struct MapLeft<S, F> {
+    #[pin]
+    inner: S,
+    f: F
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B, T, F: FnMut(A) -> T> Stream for MapLeft<S, F> {
+    type Item = Either<T, B>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.inner.poll_next(cx).map(|x| x.map(|x| x.map_left(this.f)))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Stream that maps the right values of a stream of ```Either``` values
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[derive(Debug, Clone)]
+pub struct MapRight<S, F> {
+    #[pin]
+    inner: S,
+    f: F
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B, T, F: FnMut(B) -> T> Stream for MapRight<S, F> {
+    type Item = Either<A, T>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.inner.poll_next(cx).map(|x| x.map(|x| x.map_right(this.f)))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Future that collects the left and right values of a stream of ```Either``` values into two seperate collections
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct Partition<S, CA, CB> {
+    #[pin]
+    inner: S,
+    left: CA,
+    right: CB
+}
+
+impl<S: Stream<Item = Either<A, B>>, A, B, CA: Default + Extend<A>, CB: Default + Extend<B>> Future for Partition<S, CA, CB> {
+    type Output = (CA, CB);
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Left(x)) => this.left.extend(Some(x)),
+                Some(Right(x)) => this.right.extend(Some(x)),
+                None => return Poll::Ready((core::mem::take(this.left), core::mem::take(this.right)))
+            }
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        struct SplitWakers {
+            left: AtomicWaker,
+            right: AtomicWaker
+        }
+
+        impl ArcWake for SplitWakers {
+            #[inline]
+            fn wake_by_ref(arc_self: &Arc<Self>) {
+                arc_self.left.wake();
+                arc_self.right.wake();
+            }
+        }
+
+        struct SplitState<S, A, B> {
+            inner: Pin<Box<S>>,
+            left: VecDeque<A>,
+            right: VecDeque<B>,
+            done: bool
+        }
+
+        struct SplitShared<S, A, B> {
+            wakers: Arc<SplitWakers>,
+            /// Waker that wakes up both sides, used to poll the inner stream
+            waker: Waker,
+            state: Mutex<SplitState<S, A, B>>
+        }
+
+        impl<S, A, B> SplitShared<S, A, B> {
+            #[inline(always)]
+            fn lock (&self) -> MutexGuard<'_, SplitState<S, A, B>> {
+                self.state.lock().unwrap_or_else(PoisonError::into_inner)
+            }
+        }
+
+        /// Stream that returns the left values of a stream of Either values.
+        /// This stream is **thread-safe** and maintains item order.
+        /// It only remembers the waker of the last task that polled it, so it can't be cloned.
+        #[cfg_attr(docsrs, doc(cfg(all(feature = "async", feature = "std"))))]
+        pub struct SplitLeftStream<S, A, B> {
+            shared: Arc<SplitShared<S, A, B>>
+        }
+
+        impl<S: Stream<Item = Either<A, B>>, A, B> Stream for SplitLeftStream<S, A, B> {
+            type Item = A;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let shared = &self.shared;
+                shared.wakers.left.register(cx.waker());
+
+                let mut state = shared.lock();
+                if let Some(next) = state.left.pop_front() {
+                    return Poll::Ready(Some(next));
+                }
+
+                if state.done {
+                    return Poll::Ready(None);
+                }
+
+                let mut inner_cx = Context::from_waker(&shared.waker);
+                loop {
+                    match ready!(state.inner.as_mut().poll_next(&mut inner_cx)) {
+                        Some(Left(value)) => return Poll::Ready(Some(value)),
+                        Some(Right(value)) => {
+                            state.right.push_back(value);
+                            shared.wakers.right.wake();
+                        },
+                        None => {
+                            state.done = true;
+                            shared.wakers.right.wake();
+                            return Poll::Ready(None)
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = self.shared.lock();
+                let len = state.left.len();
+                match state.done {
+                    true => (len, Some(len)),
+                    false => (len, state.inner.size_hint().1.and_then(|max| max.checked_add(len)))
+                }
+            }
+        }
+
+        impl<S, A, B> core::fmt::Debug for SplitLeftStream<S, A, B> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("SplitLeftStream").finish_non_exhaustive()
+            }
+        }
+
+        /// Stream that returns the right values of a stream of Either values.
+        /// This stream is **thread-safe** and maintains item order.
+        /// It only remembers the waker of the last task that polled it, so it can't be cloned.
+        #[cfg_attr(docsrs, doc(cfg(all(feature = "async", feature = "std"))))]
+        pub struct SplitRightStream<S, A, B> {
+            shared: Arc<SplitShared<S, A, B>>
+        }
+
+        impl<S: Stream<Item = Either<A, B>>, A, B> Stream for SplitRightStream<S, A, B> {
+            type Item = B;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let shared = &self.shared;
+                shared.wakers.right.register(cx.waker());
+
+                let mut state = shared.lock();
+                if let Some(next) = state.right.pop_front() {
+                    return Poll::Ready(Some(next));
+                }
+
+                if state.done {
+                    return Poll::Ready(None);
+                }
+
+                let mut inner_cx = Context::from_waker(&shared.waker);
+                loop {
+                    match ready!(state.inner.as_mut().poll_next(&mut inner_cx)) {
+                        Some(Right(value)) => return Poll::Ready(Some(value)),
+                        Some(Left(value)) => {
+                            state.left.push_back(value);
+                            shared.wakers.left.wake();
+                        },
+                        None => {
+                            state.done = true;
+                            shared.wakers.left.wake();
+                            return Poll::Ready(None)
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = self.shared.lock();
+                let len = state.right.len();
+                match state.done {
+                    true => (len, Some(len)),
+                    false => (len, state.inner.size_hint().1.and_then(|max| max.checked_add(len)))
+                }
+            }
+        }
+
+        impl<S, A, B> core::fmt::Debug for SplitRightStream<S, A, B> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("SplitRightStream").finish_non_exhaustive()
+            }
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A: AsyncRead, B: AsyncRead> AsyncRead for Either<A, B> {
+    #[inline(always)]
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_read(cx, buf),
+            EitherProj::Right(x) => x.poll_read(cx, buf),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_read_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &mut [IoSliceMut<'_>]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_read_vectored(cx, bufs),
+            EitherProj::Right(x) => x.poll_read_vectored(cx, bufs),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A: AsyncWrite, B: AsyncWrite> AsyncWrite for Either<A, B> {
+    #[inline(always)]
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_write(cx, buf),
+            EitherProj::Right(x) => x.poll_write(cx, buf),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_write_vectored(cx, bufs),
+            EitherProj::Right(x) => x.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_flush(cx),
+            EitherProj::Right(x) => x.poll_flush(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_close(cx),
+            EitherProj::Right(x) => x.poll_close(cx),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A: AsyncBufRead, B: AsyncBufRead> AsyncBufRead for Either<A, B> {
+    #[inline(always)]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_fill_buf(cx),
+            EitherProj::Right(x) => x.poll_fill_buf(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.project() {
+            EitherProj::Left(x) => x.consume(amt),
+            EitherProj::Right(x) => x.consume(amt),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A: AsyncSeek, B: AsyncSeek> AsyncSeek for Either<A, B> {
+    #[inline(always)]
+    fn poll_seek(self: Pin<&mut Self>, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<io::Result<u64>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_seek(cx, pos),
+            EitherProj::Right(x) => x.poll_seek(cx, pos),
+        }
+    }
+}
+
+/// Sink that forwards items to whichever side of an [`Either`] is present, when both sides have the same [`Sink::Error`]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[pin_project]
+#[repr(transparent)]
+pub struct LRSink<A, B> (#[pin] Either<A, B>);
+
+impl<A, B> Either<A, B> {
+    /// Turns an [`Either`] object with sinks with the same [`Sink::Error`] into a sink
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    #[inline(always)]
+    pub fn into_same_sink (self) -> LRSink<A, B> {
+        LRSink::new(self)
+    }
+}
+
+impl<A, B> LRSink<A, B> {
+    #[inline(always)]
+    pub const fn new (sink: Either<A, B>) -> Self {
+        Self(sink)
+    }
+
+    #[inline(always)]
+    pub fn into_inner (self) -> Either<A, B> {
+        self.0
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<Item, E, A: Sink<Item, Error = E>, B: Sink<Item, Error = E>> Sink<Item> for LRSink<A, B> {
+    type Error = E;
+
+    #[inline(always)]
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project().0.project() {
+            EitherProj::Left(x) => x.poll_ready(cx),
+            EitherProj::Right(x) => x.poll_ready(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        match self.project().0.project() {
+            EitherProj::Left(x) => x.start_send(item),
+            EitherProj::Right(x) => x.start_send(item),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project().0.project() {
+            EitherProj::Left(x) => x.poll_flush(cx),
+            EitherProj::Right(x) => x.poll_flush(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.project().0.project() {
+            EitherProj::Left(x) => x.poll_close(cx),
+            EitherProj::Right(x) => x.poll_close(cx),
+        }
+    }
+}
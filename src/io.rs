@@ -0,0 +1,150 @@
+use std::io::{Read, Write, BufRead, Seek, SeekFrom, IoSlice, IoSliceMut, Result};
+use crate::prelude::*;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A: Read, B: Read> Read for Either<A, B> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match self {
+            Left(x) => x.read(buf),
+            Right(x) => x.read(buf)
+        }
+    }
+
+    #[inline(always)]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
+        match self {
+            Left(x) => x.read_vectored(bufs),
+            Right(x) => x.read_vectored(bufs)
+        }
+    }
+
+    #[inline(always)]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+        match self {
+            Left(x) => x.read_to_end(buf),
+            Right(x) => x.read_to_end(buf)
+        }
+    }
+
+    #[inline(always)]
+    fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {
+        match self {
+            Left(x) => x.read_to_string(buf),
+            Right(x) => x.read_to_string(buf)
+        }
+    }
+
+    #[inline(always)]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        match self {
+            Left(x) => x.read_exact(buf),
+            Right(x) => x.read_exact(buf)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A: Write, B: Write> Write for Either<A, B> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self {
+            Left(x) => x.write(buf),
+            Right(x) => x.write(buf)
+        }
+    }
+
+    #[inline(always)]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+        match self {
+            Left(x) => x.write_vectored(bufs),
+            Right(x) => x.write_vectored(bufs)
+        }
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            Left(x) => x.flush(),
+            Right(x) => x.flush()
+        }
+    }
+
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        match self {
+            Left(x) => x.write_all(buf),
+            Right(x) => x.write_all(buf)
+        }
+    }
+
+    #[inline(always)]
+    fn write_fmt(&mut self, fmt: core::fmt::Arguments<'_>) -> Result<()> {
+        match self {
+            Left(x) => x.write_fmt(fmt),
+            Right(x) => x.write_fmt(fmt)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A: BufRead, B: BufRead> BufRead for Either<A, B> {
+    #[inline(always)]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        match self {
+            Left(x) => x.fill_buf(),
+            Right(x) => x.fill_buf()
+        }
+    }
+
+    #[inline(always)]
+    fn consume(&mut self, amt: usize) {
+        match self {
+            Left(x) => x.consume(amt),
+            Right(x) => x.consume(amt)
+        }
+    }
+
+    #[inline(always)]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
+        match self {
+            Left(x) => x.read_until(byte, buf),
+            Right(x) => x.read_until(byte, buf)
+        }
+    }
+
+    #[inline(always)]
+    fn read_line(&mut self, buf: &mut String) -> Result<usize> {
+        match self {
+            Left(x) => x.read_line(buf),
+            Right(x) => x.read_line(buf)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A: Seek, B: Seek> Seek for Either<A, B> {
+    #[inline(always)]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        match self {
+            Left(x) => x.seek(pos),
+            Right(x) => x.seek(pos)
+        }
+    }
+
+    #[inline(always)]
+    fn rewind(&mut self) -> Result<()> {
+        match self {
+            Left(x) => x.rewind(),
+            Right(x) => x.rewind()
+        }
+    }
+
+    #[inline(always)]
+    fn stream_position(&mut self) -> Result<u64> {
+        match self {
+            Left(x) => x.stream_position(),
+            Right(x) => x.stream_position()
+        }
+    }
+}
@@ -0,0 +1,620 @@
+use core::{iter::{FusedIterator, Fuse, Peekable}, fmt::Debug, cmp::Ordering};
+use crate::{prelude::*, These};
+
+macro_rules! impl_debug {
+    ($($name:ident<$($gen:ident),+> { $($field:ident),+ }),+ $(,)?) => {
+        $(
+            impl<$($gen),+> Debug for $name<$($gen),+> where I: Debug {
+                fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct(stringify!($name))
+                        $(.field(stringify!($field), &self.$field))+
+                        .finish_non_exhaustive()
+                }
+            }
+        )+
+    };
+}
+
+/// Iterator over the left values of an iterator of ```Either``` values
+#[derive(Debug, Clone)]
+pub struct Lefts<I> {
+    pub(super) inner: I
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for Lefts<I> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(Either::left)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B> DoubleEndedIterator for Lefts<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().rev().find_map(Either::left)
+    }
+}
+
+impl<I: FusedIterator<Item = Either<A, B>>, A, B> FusedIterator for Lefts<I> {}
+
+/// Iterator over the right values of an iterator of ```Either``` values
+#[derive(Debug, Clone)]
+pub struct Rights<I> {
+    pub(super) inner: I
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for Rights<I> {
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(Either::right)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B> DoubleEndedIterator for Rights<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().rev().find_map(Either::right)
+    }
+}
+
+impl<I: FusedIterator<Item = Either<A, B>>, A, B> FusedIterator for Rights<I> {}
+
+/// Iterator that maps the left values of an iterator of ```Either``` values, leaving the right ones untouched
+#[derive(Clone)]
+pub struct MapLeft<I, F> {
+    pub(super) inner: I,
+    pub(super) f: F
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, T, F: FnMut(A) -> T> Iterator for MapLeft<I, F> {
+    type Item = Either<T, B>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| x.map_left(&mut self.f))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, T, F: FnMut(A) -> T> DoubleEndedIterator for MapLeft<I, F> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| x.map_left(&mut self.f))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B, T, F: FnMut(A) -> T> ExactSizeIterator for MapLeft<I, F> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, T, F: FnMut(A) -> T> FusedIterator for MapLeft<I, F> {}
+
+/// Iterator that maps the right values of an iterator of ```Either``` values, leaving the left ones untouched
+#[derive(Clone)]
+pub struct MapRight<I, F> {
+    pub(super) inner: I,
+    pub(super) f: F
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, T, F: FnMut(B) -> T> Iterator for MapRight<I, F> {
+    type Item = Either<A, T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| x.map_right(&mut self.f))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, T, F: FnMut(B) -> T> DoubleEndedIterator for MapRight<I, F> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| x.map_right(&mut self.f))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B, T, F: FnMut(B) -> T> ExactSizeIterator for MapRight<I, F> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, T, F: FnMut(B) -> T> FusedIterator for MapRight<I, F> {}
+
+/// Iterator that maps both the left and right values of an iterator of ```Either``` values
+#[derive(Clone)]
+pub struct MapEither<I, F, G> {
+    pub(super) inner: I,
+    pub(super) left: F,
+    pub(super) right: G
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, L, R, F: FnMut(A) -> L, G: FnMut(B) -> R> Iterator for MapEither<I, F, G> {
+    type Item = Either<L, R>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| x.map(&mut self.left, &mut self.right))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, L, R, F: FnMut(A) -> L, G: FnMut(B) -> R> DoubleEndedIterator for MapEither<I, F, G> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| x.map(&mut self.left, &mut self.right))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B, L, R, F: FnMut(A) -> L, G: FnMut(B) -> R> ExactSizeIterator for MapEither<I, F, G> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, L, R, F: FnMut(A) -> L, G: FnMut(B) -> R> FusedIterator for MapEither<I, F, G> {}
+
+/// Iterator that skips the left values of an iterator of ```Either``` values that don't match a predicate, leaving the right ones untouched
+#[derive(Clone)]
+pub struct FilterLeft<I, P> {
+    pub(super) inner: I,
+    pub(super) predicate: P
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, P: FnMut(&A) -> bool> Iterator for FilterLeft<I, P> {
+    type Item = Either<A, B>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.inner.find(|x| x.as_ref().left().is_none_or(&mut *predicate))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, P: FnMut(&A) -> bool> DoubleEndedIterator for FilterLeft<I, P> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.inner.rfind(|x| x.as_ref().left().is_none_or(&mut *predicate))
+    }
+}
+
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, P: FnMut(&A) -> bool> FusedIterator for FilterLeft<I, P> {}
+
+/// Iterator that maps every left value of an iterator of ```Either``` values into an iterator, flattening the result and leaving the right values untouched
+pub struct FlatMapLeft<I, U: IntoIterator, F> {
+    pub(super) inner: I,
+    pub(super) f: F,
+    pub(super) front: Option<U::IntoIter>,
+    pub(super) back: Option<U::IntoIter>
+}
+
+impl<I: Clone, U: IntoIterator, F: Clone> Clone for FlatMapLeft<I, U, F> where U::IntoIter: Clone {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), f: self.f.clone(), front: self.front.clone(), back: self.back.clone() }
+    }
+}
+
+impl<I: Debug, U: IntoIterator, F> Debug for FlatMapLeft<I, U, F> where U::IntoIter: Debug {
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlatMapLeft")
+            .field("inner", &self.inner)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, U: IntoIterator, F: FnMut(A) -> U> Iterator for FlatMapLeft<I, U, F> {
+    type Item = Either<U::Item, B>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(front) = &mut self.front {
+                match front.next() {
+                    Some(x) => return Some(Left(x)),
+                    None => self.front = None
+                }
+            }
+
+            match self.inner.next() {
+                Some(Left(x)) => self.front = Some((self.f)(x).into_iter()),
+                Some(Right(x)) => return Some(Right(x)),
+                None => return and_then_or_clear(&mut self.back, Iterator::next).map(Left)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (front_min, front_max) = self.front.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let (back_min, back_max) = self.back.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let min = front_min.saturating_add(back_min);
+
+        match (self.inner.size_hint(), front_max, back_max) {
+            ((_, Some(0)), Some(front), Some(back)) => (min, front.checked_add(back)),
+            _ => (min, None)
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, U: IntoIterator, F: FnMut(A) -> U> DoubleEndedIterator for FlatMapLeft<I, U, F> where U::IntoIter: DoubleEndedIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(back) = &mut self.back {
+                match back.next_back() {
+                    Some(x) => return Some(Left(x)),
+                    None => self.back = None
+                }
+            }
+
+            match self.inner.next_back() {
+                Some(Left(x)) => self.back = Some((self.f)(x).into_iter()),
+                Some(Right(x)) => return Some(Right(x)),
+                None => return and_then_or_clear(&mut self.front, DoubleEndedIterator::next_back).map(Left)
+            }
+        }
+    }
+}
+
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, U: IntoIterator, F: FnMut(A) -> U> FusedIterator for FlatMapLeft<I, U, F> {}
+
+/// Calls ```f``` on the iterator inside ```opt```, clearing it once it's exhausted so it's never polled again
+#[inline]
+fn and_then_or_clear<T, U> (opt: &mut Option<T>, f: impl FnOnce(&mut T) -> Option<U>) -> Option<U> {
+    let next = f(opt.as_mut()?);
+    if next.is_none() {
+        *opt = None;
+    }
+    next
+}
+
+/// Iterator that returns the left values of an iterator of ```Either``` values, computing them from the right ones when needed
+#[derive(Clone)]
+pub struct LeftOrElse<I, F> {
+    pub(super) inner: I,
+    pub(super) f: F
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, F: FnMut(B) -> A> Iterator for LeftOrElse<I, F> {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| x.fold_left(&mut self.f))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, F: FnMut(B) -> A> DoubleEndedIterator for LeftOrElse<I, F> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| x.fold_left(&mut self.f))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B, F: FnMut(B) -> A> ExactSizeIterator for LeftOrElse<I, F> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, F: FnMut(B) -> A> FusedIterator for LeftOrElse<I, F> {}
+
+/// Iterator that calls a function with a reference to every left value of an iterator of ```Either``` values before passing it on
+#[derive(Clone)]
+pub struct InspectLeft<I, F> {
+    pub(super) inner: I,
+    pub(super) f: F
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, F: FnMut(&A)> Iterator for InspectLeft<I, F> {
+    type Item = Either<A, B>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.inner.next();
+        if let Some(Left(x)) = &next {
+            (self.f)(x)
+        }
+        next
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B, F: FnMut(&A)> DoubleEndedIterator for InspectLeft<I, F> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = self.inner.next_back();
+        if let Some(Left(x)) = &next {
+            (self.f)(x)
+        }
+        next
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B, F: FnMut(&A)> ExactSizeIterator for InspectLeft<I, F> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B, F: FnMut(&A)> FusedIterator for InspectLeft<I, F> {}
+
+/// Iterator that unwraps the values of an iterator of ```Either``` values, converting the right ones into the left type
+#[derive(Debug, Clone)]
+pub struct IntoSame<I> {
+    pub(super) inner: I
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B: Into<A>> Iterator for IntoSame<I> {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| x.fold_left(Into::into))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = Either<A, B>>, A, B: Into<A>> DoubleEndedIterator for IntoSame<I> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| x.fold_left(Into::into))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Either<A, B>>, A, B: Into<A>> ExactSizeIterator for IntoSame<I> {}
+impl<I: FusedIterator<Item = Either<A, B>>, A, B: Into<A>> FusedIterator for IntoSame<I> {}
+
+/// Iterator that zips two iterators until both are exhausted, returning ```These``` values
+#[derive(Debug, Clone)]
+pub struct ZipLongest<I, J> {
+    pub(super) left: Fuse<I>,
+    pub(super) right: Fuse<J>
+}
+
+impl<I: Iterator, J: Iterator> Iterator for ZipLongest<I, J> {
+    type Item = These<I::Item, J::Item>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        These::from_options(self.left.next(), self.right.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_min, left_max) = self.left.size_hint();
+        let (right_min, right_max) = self.right.size_hint();
+
+        let max = match (left_max, right_max) {
+            (Some(x), Some(y)) => Some(x.max(y)),
+            _ => None
+        };
+
+        (left_min.max(right_min), max)
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator, J: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for ZipLongest<I, J> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.left.len().cmp(&self.right.len()) {
+            Ordering::Greater => self.left.next_back().map(These::Left),
+            Ordering::Less => self.right.next_back().map(These::Right),
+            Ordering::Equal => These::from_options(self.left.next_back(), self.right.next_back())
+        }
+    }
+}
+
+impl<I: ExactSizeIterator, J: ExactSizeIterator> ExactSizeIterator for ZipLongest<I, J> {}
+impl<I: Iterator, J: Iterator> FusedIterator for ZipLongest<I, J> {}
+
+/// Iterator that merges two sorted iterators, returning ```These::Both``` for the values that compare equal
+pub struct MergeJoinBy<I: Iterator, J: Iterator, F> {
+    pub(super) left: Peekable<I>,
+    pub(super) right: Peekable<J>,
+    pub(super) cmp: F
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone, F: Clone> Clone for MergeJoinBy<I, J, F> where I::Item: Clone, J::Item: Clone {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { left: self.left.clone(), right: self.right.clone(), cmp: self.cmp.clone() }
+    }
+}
+
+impl<I: Iterator + Debug, J: Iterator + Debug, F> Debug for MergeJoinBy<I, J, F> where I::Item: Debug, J::Item: Debug {
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MergeJoinBy")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator, J: Iterator, F: FnMut(&I::Item, &J::Item) -> Ordering> Iterator for MergeJoinBy<I, J, F> {
+    type Item = These<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ord = match (self.left.peek(), self.right.peek()) {
+            (Some(x), Some(y)) => (self.cmp)(x, y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None
+        };
+
+        match ord {
+            Ordering::Less => self.left.next().map(These::Left),
+            Ordering::Greater => self.right.next().map(These::Right),
+            Ordering::Equal => These::from_options(self.left.next(), self.right.next())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_min, left_max) = self.left.size_hint();
+        let (right_min, right_max) = self.right.size_hint();
+
+        let max = match (left_max, right_max) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None
+        };
+
+        (left_min.max(right_min), max)
+    }
+}
+
+impl<I: FusedIterator, J: FusedIterator, F: FnMut(&I::Item, &J::Item) -> Ordering> FusedIterator for MergeJoinBy<I, J, F> {}
+
+/// Iterator that returns the values of two iterators in turns, as ```Either``` values
+#[derive(Debug, Clone)]
+pub struct InterleaveEither<I, J> {
+    pub(super) left: Fuse<I>,
+    pub(super) right: Fuse<J>,
+    pub(super) next_left: bool
+}
+
+impl<I: Iterator, J: Iterator> Iterator for InterleaveEither<I, J> {
+    type Item = Either<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let take_left = self.next_left;
+        self.next_left = !take_left;
+
+        match take_left {
+            true => self.left.next().map(Left).or_else(|| self.right.next().map(Right)),
+            false => self.right.next().map(Right).or_else(|| self.left.next().map(Left))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_hints(self.left.size_hint(), self.right.size_hint())
+    }
+}
+
+impl<I: Iterator, J: Iterator> FusedIterator for InterleaveEither<I, J> {}
+
+/// Iterator that merges two sorted iterators, returning their values as ```Either``` values
+pub struct MergeEitherBy<I: Iterator, J: Iterator, F> {
+    pub(super) left: Peekable<I>,
+    pub(super) right: Peekable<J>,
+    pub(super) cmp: F
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone, F: Clone> Clone for MergeEitherBy<I, J, F> where I::Item: Clone, J::Item: Clone {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { left: self.left.clone(), right: self.right.clone(), cmp: self.cmp.clone() }
+    }
+}
+
+impl<I: Iterator + Debug, J: Iterator + Debug, F> Debug for MergeEitherBy<I, J, F> where I::Item: Debug, J::Item: Debug {
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MergeEitherBy")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator, J: Iterator, F: FnMut(&I::Item, &J::Item) -> Ordering> Iterator for MergeEitherBy<I, J, F> {
+    type Item = Either<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let left = match (self.left.peek(), self.right.peek()) {
+            (Some(x), Some(y)) => (self.cmp)(x, y) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None
+        };
+
+        match left {
+            true => self.left.next().map(Left),
+            false => self.right.next().map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_hints(self.left.size_hint(), self.right.size_hint())
+    }
+}
+
+impl<I: FusedIterator, J: FusedIterator, F: FnMut(&I::Item, &J::Item) -> Ordering> FusedIterator for MergeEitherBy<I, J, F> {}
+
+/// Iterator that returns the values of one iterator followed by the values of another, as ```Either``` values
+#[derive(Debug, Clone)]
+pub struct ChainEither<I, J> {
+    pub(super) left: Fuse<I>,
+    pub(super) right: Fuse<J>
+}
+
+impl<I: Iterator, J: Iterator> Iterator for ChainEither<I, J> {
+    type Item = Either<I::Item, J::Item>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left.next().map(Left).or_else(|| self.right.next().map(Right))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_hints(self.left.size_hint(), self.right.size_hint())
+    }
+}
+
+impl<I: DoubleEndedIterator, J: DoubleEndedIterator> DoubleEndedIterator for ChainEither<I, J> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.right.next_back().map(Right).or_else(|| self.left.next_back().map(Left))
+    }
+}
+
+impl<I: Iterator, J: Iterator> FusedIterator for ChainEither<I, J> {}
+
+#[inline]
+fn sum_hints ((left_min, left_max): (usize, Option<usize>), (right_min, right_max): (usize, Option<usize>)) -> (usize, Option<usize>) {
+    let max = match (left_max, right_max) {
+        (Some(x), Some(y)) => x.checked_add(y),
+        _ => None
+    };
+
+    (left_min.saturating_add(right_min), max)
+}
+
+impl_debug! {
+    MapLeft<I, F> { inner },
+    MapRight<I, F> { inner },
+    MapEither<I, F, G> { inner },
+    FilterLeft<I, P> { inner },
+    LeftOrElse<I, F> { inner },
+    InspectLeft<I, F> { inner },
+}
@@ -0,0 +1,1574 @@
+use core::{iter::{FusedIterator, Sum, Product}, cell::RefCell, mem::MaybeUninit, cmp};
+use crate::prelude::*;
+
+mod adapters;
+pub use adapters::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "rayon")] {
+        mod rayon;
+        pub use self::rayon::*;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "alloc")] {
+        use alloc::rc::Rc;
+        use alloc::collections::VecDeque;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        use alloc::sync::Arc;
+        use std::sync::{Mutex, MutexGuard, Condvar};
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for Either<A, B> {
+    type Item = Either<A::Item, B::Item>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Left(x) => x.next().map(Left),
+            Right(x) => x.next().map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Left(x) => x.size_hint(),
+            Right(x) => x.size_hint()
+        }
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            Left(x) => x.nth(n).map(Left),
+            Right(x) => x.nth(n).map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        match self {
+            Left(x) => x.count(),
+            Right(x) => x.count()
+        }
+    }
+
+    #[inline(always)]
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            Left(x) => x.last().map(Left),
+            Right(x) => x.last().map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn fold<T, F: FnMut(T, Self::Item) -> T> (self, init: T, mut f: F) -> T {
+        match self {
+            Left(x) => x.fold(init, |acc, x| f(acc, Left(x))),
+            Right(x) => x.fold(init, |acc, x| f(acc, Right(x)))
+        }
+    }
+}
+
+impl<A: ExactSizeIterator, B: ExactSizeIterator> ExactSizeIterator for Either<A, B> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match self {
+            Left(x) => x.len(),
+            Right(x) => x.len()
+        }
+    }
+}
+
+impl<A: DoubleEndedIterator, B: DoubleEndedIterator> DoubleEndedIterator for Either<A, B> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Left(x) => x.next_back().map(Left),
+            Right(x) => x.next_back().map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            Left(x) => x.nth_back(n).map(Left),
+            Right(x) => x.nth_back(n).map(Right)
+        }
+    }
+
+    #[inline(always)]
+    fn rfold<T, F: FnMut(T, Self::Item) -> T> (self, init: T, mut f: F) -> T {
+        match self {
+            Left(x) => x.rfold(init, |acc, x| f(acc, Left(x))),
+            Right(x) => x.rfold(init, |acc, x| f(acc, Right(x)))
+        }
+    }
+}
+
+impl<A: FusedIterator, B: FusedIterator> FusedIterator for Either<A, B> {}
+
+/// Collects the left values until the first right value, which is returned instead
+impl<A, B, CA: FromIterator<A>> FromIterator<Either<A, B>> for Either<CA, B> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Either<A, B>>> (iter: I) -> Self {
+        let mut residual = None;
+        let collection = CA::from_iter(Shunt { inner: iter.into_iter(), residual: &mut residual });
+
+        match residual {
+            Some(x) => Right(x),
+            None => Left(collection)
+        }
+    }
+}
+
+/// Sums the left values until the first right value, which is returned instead
+impl<A, B, S: Sum<A>> Sum<Either<A, B>> for Either<S, B> {
+    #[inline]
+    fn sum<I: Iterator<Item = Either<A, B>>> (iter: I) -> Self {
+        let mut residual = None;
+        let sum = S::sum(Shunt { inner: iter, residual: &mut residual });
+
+        match residual {
+            Some(x) => Right(x),
+            None => Left(sum)
+        }
+    }
+}
+
+/// Multiplies the left values until the first right value, which is returned instead
+impl<A, B, P: Product<A>> Product<Either<A, B>> for Either<P, B> {
+    #[inline]
+    fn product<I: Iterator<Item = Either<A, B>>> (iter: I) -> Self {
+        let mut residual = None;
+        let product = P::product(Shunt { inner: iter, residual: &mut residual });
+
+        match residual {
+            Some(x) => Right(x),
+            None => Left(product)
+        }
+    }
+}
+
+/// Iterator over the left values of ```inner```, that stops at the first right value and stores it in ```residual```
+struct Shunt<'a, I, B> {
+    inner: I,
+    residual: &'a mut Option<B>
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for Shunt<'_, I, B> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+
+        match self.inner.next()? {
+            Left(x) => Some(x),
+            Right(x) => {
+                *self.residual = Some(x);
+                None
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.residual {
+            Some(_) => (0, Some(0)),
+            None => (0, self.inner.size_hint().1)
+        }
+    }
+}
+
+/// Iterator over the items of either of two iterators with the same [`Iterator::Item`]
+#[repr(transparent)]
+#[derive(Debug, Clone)]
+pub struct LRIter<A, B> (pub(crate) Either<A, B>);
+
+impl<A: IntoIterator, B: IntoIterator> Either<A, B> {
+    /// Turns an [`Either`] object with collections or iterators with the same [`Iterator::Item`] into an iterator
+    #[inline(always)]
+    pub fn into_same_iter (self) -> LRIter<A::IntoIter, B::IntoIter> where B: IntoIterator<Item = A::Item> {
+        LRIter::new(self.into_iter_either())
+    }
+
+    /// Turns an [`Either`] object with collections or iterators into an iterator of [`Either`] values
+    #[inline(always)]
+    pub fn into_iter_either (self) -> Either<A::IntoIter, B::IntoIter> {
+        self.map(IntoIterator::into_iter, IntoIterator::into_iter)
+    }
+}
+
+impl<A, B> Either<A, B> {
+    /// Returns an iterator over references to the values of the collection inside
+    #[inline(always)]
+    pub fn iter<'a, T: 'a> (&'a self) -> LRIter<<&'a A as IntoIterator>::IntoIter, <&'a B as IntoIterator>::IntoIter> where &'a A: IntoIterator<Item = T>, &'a B: IntoIterator<Item = T> {
+        self.as_ref().into_same_iter()
+    }
+
+    /// Returns an iterator over mutable references to the values of the collection inside.
+    /// ```&mut Either``` can't implement ```IntoIterator``` itself, since it's already an iterator when both sides are.
+    #[inline(always)]
+    pub fn iter_mut<'a, T: 'a> (&'a mut self) -> LRIter<<&'a mut A as IntoIterator>::IntoIter, <&'a mut B as IntoIterator>::IntoIter> where &'a mut A: IntoIterator<Item = T>, &'a mut B: IntoIterator<Item = T> {
+        self.as_mut().into_same_iter()
+    }
+}
+
+impl<'a, T: 'a, A, B> IntoIterator for &'a Either<A, B> where &'a A: IntoIterator<Item = T>, &'a B: IntoIterator<Item = T> {
+    type Item = T;
+    type IntoIter = LRIter<<&'a A as IntoIterator>::IntoIter, <&'a B as IntoIterator>::IntoIter>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<A, B> LRIter<A, B> {
+    #[inline(always)]
+    pub const fn new (iter: Either<A,B>) -> Self {
+        Self(iter)
+    }
+
+    #[inline(always)]
+    pub fn into_inner (self) -> Either<A, B> {
+        self.0
+    }
+}
+
+impl<T, A: Iterator<Item = T>, B: Iterator<Item = T>> Iterator for LRIter<A, B> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Left(x) => x.next(),
+            Right(x) => x.next()
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            Left(x) => x.size_hint(),
+            Right(x) => x.size_hint()
+        }
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match &mut self.0 {
+            Left(x) => x.nth(n),
+            Right(x) => x.nth(n)
+        }
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        match self.0 {
+            Left(x) => x.count(),
+            Right(x) => x.count()
+        }
+    }
+
+    #[inline(always)]
+    fn last(self) -> Option<Self::Item> {
+        match self.0 {
+            Left(x) => x.last(),
+            Right(x) => x.last()
+        }
+    }
+
+    #[inline(always)]
+    fn fold<U, F: FnMut(U, Self::Item) -> U> (self, init: U, f: F) -> U {
+        match self.0 {
+            Left(x) => x.fold(init, f),
+            Right(x) => x.fold(init, f)
+        }
+    }
+}
+
+impl<T, A: ExactSizeIterator<Item = T>, B: ExactSizeIterator<Item = T>> ExactSizeIterator for LRIter<A, B> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match &self.0 {
+            Left(x) => x.len(),
+            Right(x) => x.len()
+        }
+    }
+}
+
+impl<T, A: DoubleEndedIterator<Item = T>, B: DoubleEndedIterator<Item = T>> DoubleEndedIterator for LRIter<A, B> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Left(x) => x.next_back(),
+            Right(x) => x.next_back()
+        }
+    }
+
+    #[inline(always)]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match &mut self.0 {
+            Left(x) => x.nth_back(n),
+            Right(x) => x.nth_back(n)
+        }
+    }
+
+    #[inline(always)]
+    fn rfold<U, F: FnMut(U, Self::Item) -> U> (self, init: U, f: F) -> U {
+        match self.0 {
+            Left(x) => x.rfold(init, f),
+            Right(x) => x.rfold(init, f)
+        }
+    }
+}
+
+impl<T, A: FusedIterator<Item = T>, B: FusedIterator<Item = T>> FusedIterator for LRIter<A, B> {}
+
+/// Returns an iterator that alternates between the values of ```left``` and ```right```, starting with ```left```.
+/// Once one of them is exhausted, the remaining values of the other are returned in order.
+#[inline(always)]
+pub fn interleave_either<I: IntoIterator, J: IntoIterator> (left: I, right: J) -> InterleaveEither<I::IntoIter, J::IntoIter> {
+    InterleaveEither { left: left.into_iter().fuse(), right: right.into_iter().fuse(), next_left: true }
+}
+
+/// Returns an iterator that merges ```left``` and ```right```, assuming both are sorted according to ```cmp```.
+/// When values compare equal, the left one is returned first.
+#[inline(always)]
+pub fn merge_either_by<I: IntoIterator, J: IntoIterator, F: FnMut(&I::Item, &J::Item) -> cmp::Ordering> (left: I, right: J, cmp: F) -> MergeEitherBy<I::IntoIter, J::IntoIter, F> {
+    MergeEitherBy { left: left.into_iter().peekable(), right: right.into_iter().peekable(), cmp }
+}
+
+/// Returns an iterator over the values of ```left``` followed by the values of ```right```
+#[inline(always)]
+pub fn chain_either<I: IntoIterator, J: IntoIterator> (left: I, right: J) -> ChainEither<I::IntoIter, J::IntoIter> {
+    ChainEither { left: left.into_iter().fuse(), right: right.into_iter().fuse() }
+}
+
+/// An iterator with ```Either``` items
+pub trait EitherIter<A, B>: Sized + Iterator<Item = Either<A, B>> {
+    /// Returns two seperate iterators that return the left and right values seperately.
+    /// This iterators are **not thread-safe**, but maintain item order and have less overhead than their thread-safe counterparts.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn split_either (self) -> (SplitLeft<Self, A, B>, SplitRight<Self, A, B>) {
+        self.split_either_bounded(usize::MAX, Overflow::Error)
+    }
+
+    /// Returns two seperate iterators that return the left and right values seperately,
+    /// buffering at most ```capacity``` values for each side and applying ```overflow``` once a side's buffer is full.
+    /// This iterators are **not thread-safe**, but maintain item order and have less overhead than their thread-safe counterparts.
+    ///
+    /// # Panics
+    /// Panics if ```capacity``` is zero or ```overflow``` is ```Overflow::Block```, since a single thread can't wait for itself.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    fn split_either_bounded (self, capacity: usize, overflow: Overflow<A, B>) -> (SplitLeft<Self, A, B>, SplitRight<Self, A, B>) {
+        assert!(capacity > 0, "split buffers must have a capacity of at least one");
+        assert!(!matches!(overflow, Overflow::Block), "non-thread-safe split iterators can't block");
+
+        let init_cap = match self.size_hint() {
+            (_, Some(max)) => max,
+            (min, None) => min.max(7)
+        }.min(capacity);
+
+        let inner = Rc::new(RefCell::new(SplitState { inner: self, pending: None, capacity, overflow }));
+        let left = Rc::new(RefCell::new(VecDeque::with_capacity(init_cap)));
+        let right = Rc::new(RefCell::new(VecDeque::with_capacity(init_cap)));
+
+        (
+            SplitLeft {
+                inner: inner.clone(),
+                this: left.clone(),
+                other: right.clone(),
+            },
+
+            SplitRight {
+                inner,
+                this: right,
+                other: left,
+            }
+        )
+    }
+
+    /// Returns two seperate iterators that return the left and right values seperately.
+    /// This iterators are **thread-safe** and maintain item order, but add more overhead than their non-thread-safe counterparts.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn split_either_arc (self) -> (SplitLeftArc<Self, A, B>, SplitRightArc<Self, A, B>) {
+        self.split_either_arc_bounded(usize::MAX, Overflow::Error)
+    }
+
+    /// Returns two seperate iterators that return the left and right values seperately,
+    /// buffering at most ```capacity``` values for each side and applying ```overflow``` once a side's buffer is full.
+    /// This iterators are **thread-safe** and maintain item order, but add more overhead than their non-thread-safe counterparts.
+    ///
+    /// With ```Overflow::Block```, a side that has to buffer a value waits until the other side makes room for it (or is dropped),
+    /// so both sides must be consumed from different threads.
+    ///
+    /// # Panics
+    /// Panics if ```capacity``` is zero.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    fn split_either_arc_bounded (self, capacity: usize, overflow: Overflow<A, B>) -> (SplitLeftArc<Self, A, B>, SplitRightArc<Self, A, B>) {
+        assert!(capacity > 0, "split buffers must have a capacity of at least one");
+
+        let init_cap = match self.size_hint() {
+            (_, Some(max)) => max,
+            (min, None) => min.max(7)
+        }.min(capacity);
+
+        let inner = Arc::new(Mutex::new(SplitState { inner: self, pending: None, capacity, overflow }));
+        let left = Arc::new(SplitBuffer::new(init_cap));
+        let right = Arc::new(SplitBuffer::new(init_cap));
+
+        (
+            SplitLeftArc {
+                inner: inner.clone(),
+                this: left.clone(),
+                other: right.clone(),
+            },
+
+            SplitRightArc {
+                inner,
+                this: right,
+                other: left,
+            }
+        )
+    }
+
+    /// Returns two seperate iterators that return the left and right values seperately.
+    /// This iterators are **thread-safe** and maintain item order, at the cost of sharing a single lock between both sides.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn split_either_sync (self) -> (SplitLeftSync<Self, A, B>, SplitRightSync<Self, A, B>) {
+        let init_cap = match self.size_hint() {
+            (_, Some(max)) => max,
+            (min, None) => min.max(7)
+        };
+
+        let inner = Arc::new(Mutex::new(SplitSyncState {
+            inner: self,
+            left: VecDeque::with_capacity(init_cap),
+            right: VecDeque::with_capacity(init_cap)
+        }));
+
+        (SplitLeftSync { inner: inner.clone() }, SplitRightSync { inner })
+    }
+
+    /// Returns an iterator over the left values, discarding the right ones
+    #[inline(always)]
+    fn lefts (self) -> Lefts<Self> {
+        Lefts { inner: self }
+    }
+
+    /// Returns an iterator over the right values, discarding the left ones
+    #[inline(always)]
+    fn rights (self) -> Rights<Self> {
+        Rights { inner: self }
+    }
+
+    /// Returns an iterator that maps the left values, leaving the right ones untouched
+    #[inline(always)]
+    fn map_left<T, F: FnMut(A) -> T> (self, f: F) -> MapLeft<Self, F> {
+        MapLeft { inner: self, f }
+    }
+
+    /// Returns an iterator that maps the right values, leaving the left ones untouched
+    #[inline(always)]
+    fn map_right<T, F: FnMut(B) -> T> (self, f: F) -> MapRight<Self, F> {
+        MapRight { inner: self, f }
+    }
+
+    /// Returns an iterator that maps the left values with ```left``` and the right values with ```right```
+    #[inline(always)]
+    fn map_either<L, R, F: FnMut(A) -> L, G: FnMut(B) -> R> (self, left: F, right: G) -> MapEither<Self, F, G> {
+        MapEither { inner: self, left, right }
+    }
+
+    /// Returns an iterator that skips the left values that don't match ```predicate```, leaving the right ones untouched
+    #[inline(always)]
+    fn filter_left<P: FnMut(&A) -> bool> (self, predicate: P) -> FilterLeft<Self, P> {
+        FilterLeft { inner: self, predicate }
+    }
+
+    /// Returns an iterator that replaces every left value with the left values of the iterator returned by ```f```, leaving the right ones untouched
+    #[inline(always)]
+    fn flat_map_left<U: IntoIterator, F: FnMut(A) -> U> (self, f: F) -> FlatMapLeft<Self, U, F> {
+        FlatMapLeft { inner: self, f, front: None, back: None }
+    }
+
+    /// Returns an iterator over the left values, computing them from the right values with ```f``` when needed
+    #[inline(always)]
+    fn left_or_else<F: FnMut(B) -> A> (self, f: F) -> LeftOrElse<Self, F> {
+        LeftOrElse { inner: self, f }
+    }
+
+    /// Returns an iterator that calls ```f``` with a reference to every left value before passing it on
+    #[inline(always)]
+    fn inspect_left<F: FnMut(&A)> (self, f: F) -> InspectLeft<Self, F> {
+        InspectLeft { inner: self, f }
+    }
+
+    /// Consumes the iterator, returning the number of left and right values
+    #[inline]
+    fn count_sides (self) -> (usize, usize) {
+        self.fold((0, 0), |(left, right), x| match x {
+            Left(_) => (left + 1, right),
+            Right(_) => (left, right + 1)
+        })
+    }
+
+    /// Returns ```true``` if every value is a left value, stopping at the first right value
+    #[inline(always)]
+    fn all_left (&mut self) -> bool {
+        self.all(|x| x.is_left())
+    }
+
+    /// Returns ```true``` if any value is a right value, stopping at the first one
+    #[inline(always)]
+    fn any_right (&mut self) -> bool {
+        self.any(|x| x.is_right())
+    }
+
+    /// Returns an iterator over the values inside, converting right values into the left type (a no-op for ```Either<T, T>``` values)
+    #[inline(always)]
+    fn into_same (self) -> IntoSame<Self> where B: Into<A> {
+        IntoSame { inner: self }
+    }
+
+    /// Consumes the iterator, collecting the left and right values into two seperate collections
+    #[inline]
+    fn partition_either<CA: Default + Extend<A>, CB: Default + Extend<B>> (self) -> (CA, CB) {
+        let mut left = CA::default();
+        let mut right = CB::default();
+
+        for x in self {
+            match x {
+                Left(x) => left.extend(Some(x)),
+                Right(x) => right.extend(Some(x))
+            }
+        }
+
+        (left, right)
+    }
+
+    /// Collects the right values until the first left value, which is returned instead. Mirrors the ```FromIterator``` implementation of ```Either```
+    #[inline(always)]
+    fn collect_right<CB: FromIterator<B>> (self) -> Either<A, CB> {
+        self.map(Either::inverse).collect::<Either<CB, A>>().inverse()
+    }
+
+    /// Sums the right values until the first left value, which is returned instead. Mirrors the ```Sum``` implementation of ```Either```
+    #[inline(always)]
+    fn sum_right<S: Sum<B>> (self) -> Either<A, S> {
+        self.map(Either::inverse).sum::<Either<S, A>>().inverse()
+    }
+
+    /// Multiplies the right values until the first left value, which is returned instead. Mirrors the ```Product``` implementation of ```Either```
+    #[inline(always)]
+    fn product_right<P: Product<B>> (self) -> Either<A, P> {
+        self.map(Either::inverse).product::<Either<P, A>>().inverse()
+    }
+
+    /// Returns two seperate iterators that return the left and right values seperately, buffering values in ```storage``` instead of allocating.
+    /// Each side buffers at most ```N``` values, after which the other side's ```try_next``` returns ```Err(BufferFull)``` (and it's ```next``` returns ```None```) until it makes room.
+    /// This iterators are **not thread-safe**, but maintain item order.
+    #[inline(always)]
+    fn split_either_in<const N: usize> (self, storage: &mut SplitStorage<Self, A, B, N>) -> (SplitLeftIn<'_, Self, A, B, N>, SplitRightIn<'_, Self, A, B, N>) {
+        let state = &*storage.state.insert(RefCell::new(SplitInState {
+            inner: self,
+            pending: None,
+            left: RingBuffer::new(),
+            right: RingBuffer::new()
+        }));
+
+        (SplitLeftIn { state }, SplitRightIn { state })
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B> EitherIter<A, B> for I {}
+
+/// Extension methods for iterators that produce ```Either``` values
+pub trait IteratorExt: Sized + Iterator {
+    /// Consumes the iterator, collecting the values mapped to ```Left``` and ```Right``` by ```f``` into two seperate collections
+    #[inline]
+    fn partition_map<A, B, CA: Default + Extend<A>, CB: Default + Extend<B>, F: FnMut(Self::Item) -> Either<A, B>> (self, f: F) -> (CA, CB) {
+        self.map(f).partition_either()
+    }
+
+    /// Zips the iterator with another one until both are exhausted, returning ```These::Both``` while both have values left
+    #[inline(always)]
+    fn zip_longest<J: IntoIterator> (self, other: J) -> ZipLongest<Self, J::IntoIter> {
+        ZipLongest { left: self.fuse(), right: other.into_iter().fuse() }
+    }
+
+    /// Merges the iterator with another one, assuming both are sorted according to ```cmp```.
+    /// Values that compare equal are returned together as ```These::Both```, and the rest on their own side.
+    #[inline(always)]
+    fn merge_join_by<J: IntoIterator, F: FnMut(&Self::Item, &J::Item) -> cmp::Ordering> (self, other: J, cmp: F) -> MergeJoinBy<Self, J::IntoIter, F> {
+        MergeJoinBy { left: self.peekable(), right: other.into_iter().peekable(), cmp }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Error returned by split iterators when the next value can't be reached without overflowing the other side's buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BufferFull;
+
+impl core::fmt::Display for BufferFull {
+    #[inline(always)]
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the other side's buffer is full")
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl std::error::Error for BufferFull {}
+
+/// Ends ```Iterator::next``` early if the other side's buffer is full.
+/// The value that didn't fit is kept, so ```next``` picks up from it once the other side makes room.
+#[inline(always)]
+fn stop_if_full<T> (next: Result<Option<T>, BufferFull>) -> Option<T> {
+    next.unwrap_or(None)
+}
+
+/// Fixed-capacity FIFO queue
+struct RingBuffer<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    #[inline(always)]
+    fn new () -> Self {
+        // an array of uninitialized values doesn't need to be initialized
+        Self { buf: unsafe { MaybeUninit::uninit().assume_init() }, head: 0, len: 0 }
+    }
+
+    #[inline]
+    fn push_back (&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+
+        self.buf[(self.head + self.len) % N].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn pop_front (&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let value = unsafe { self.buf[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for RingBuffer<T, N> {
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..self.len).map(|i| unsafe { self.buf[(self.head + i) % N].assume_init_ref() }))
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    #[inline]
+    fn drop (&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+#[derive(Debug)]
+struct SplitInState<I, A, B, const N: usize> {
+    inner: I,
+    /// Value that didn't fit in it's side's buffer
+    pending: Option<Either<A, B>>,
+    left: RingBuffer<A, N>,
+    right: RingBuffer<B, N>
+}
+
+/// Storage for the buffers of ```split_either_in```, able to hold up to ```N``` values for each side
+#[derive(Debug)]
+pub struct SplitStorage<I, A, B, const N: usize> {
+    state: Option<RefCell<SplitInState<I, A, B, N>>>
+}
+
+impl<I, A, B, const N: usize> SplitStorage<I, A, B, N> {
+    /// Creates a new, empty storage
+    #[inline(always)]
+    pub const fn new () -> Self {
+        Self { state: None }
+    }
+}
+
+impl<I, A, B, const N: usize> Default for SplitStorage<I, A, B, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator that returns the left values of an iterator of Either values, buffering right values in a ```SplitStorage```.
+/// This iterator is **not thread-safe**, but maintains item order and doesn't allocate.
+#[derive(Debug)]
+pub struct SplitLeftIn<'a, I, A, B, const N: usize> {
+    state: &'a RefCell<SplitInState<I, A, B, N>>
+}
+
+impl<I, A, B, const N: usize> SplitLeftIn<'_, I, A, B, N> {
+    /// Returns the number of values currently buffered for this side
+    #[inline(always)]
+    pub fn buffered (&self) -> usize {
+        self.state.borrow().left.len
+    }
+
+    /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+    #[inline(always)]
+    pub fn overflowed (&self) -> bool {
+        let state = self.state.borrow();
+        matches!(state.pending, Some(Right(_))) && state.right.len == N
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, const N: usize> SplitLeftIn<'_, I, A, B, N> {
+    /// Returns the next left value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the right side's buffer
+    pub fn try_next (&mut self) -> Result<Option<A>, BufferFull> {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+
+        if let Some(next) = state.left.pop_front() {
+            return Ok(Some(next));
+        }
+
+        match state.pending.take() {
+            Some(Left(value)) => return Ok(Some(value)),
+            Some(Right(value)) => if let Err(value) = state.right.push_back(value) {
+                state.pending = Some(Right(value));
+                return Err(BufferFull);
+            },
+            None => {}
+        }
+
+        for next in state.inner.by_ref() {
+            match next {
+                Left(value) => return Ok(Some(value)),
+                Right(value) => if let Err(value) = state.right.push_back(value) {
+                    state.pending = Some(Right(value));
+                    return Err(BufferFull);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, const N: usize> Iterator for SplitLeftIn<'_, I, A, B, N> {
+    type Item = A;
+
+    /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer.
+    /// Check ```overflowed``` to tell it apart from the end of the iterator.
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        stop_if_full(self.try_next())
+    }
+
+    #[inline(always)]
+    fn size_hint (&self) -> (usize, Option<usize>) {
+        let state = self.state.borrow();
+        let (_, mut max) = state.inner.size_hint();
+        let len = state.left.len + matches!(state.pending, Some(Left(_))) as usize;
+
+        if let Some(ref mut max) = max {
+            *max += len
+        }
+
+        (len, max)
+    }
+}
+
+impl<I, A, B, const N: usize> Clone for SplitLeftIn<'_, I, A, B, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { state: self.state }
+    }
+}
+
+/// Iterator that returns the right values of an iterator of Either values, buffering left values in a ```SplitStorage```.
+/// This iterator is **not thread-safe**, but maintains item order and doesn't allocate.
+#[derive(Debug)]
+pub struct SplitRightIn<'a, I, A, B, const N: usize> {
+    state: &'a RefCell<SplitInState<I, A, B, N>>
+}
+
+impl<I, A, B, const N: usize> SplitRightIn<'_, I, A, B, N> {
+    /// Returns the number of values currently buffered for this side
+    #[inline(always)]
+    pub fn buffered (&self) -> usize {
+        self.state.borrow().right.len
+    }
+
+    /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+    #[inline(always)]
+    pub fn overflowed (&self) -> bool {
+        let state = self.state.borrow();
+        matches!(state.pending, Some(Left(_))) && state.left.len == N
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, const N: usize> SplitRightIn<'_, I, A, B, N> {
+    /// Returns the next right value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the left side's buffer
+    pub fn try_next (&mut self) -> Result<Option<B>, BufferFull> {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+
+        if let Some(next) = state.right.pop_front() {
+            return Ok(Some(next));
+        }
+
+        match state.pending.take() {
+            Some(Right(value)) => return Ok(Some(value)),
+            Some(Left(value)) => if let Err(value) = state.left.push_back(value) {
+                state.pending = Some(Left(value));
+                return Err(BufferFull);
+            },
+            None => {}
+        }
+
+        for next in state.inner.by_ref() {
+            match next {
+                Right(value) => return Ok(Some(value)),
+                Left(value) => if let Err(value) = state.left.push_back(value) {
+                    state.pending = Some(Left(value));
+                    return Err(BufferFull);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<I: Iterator<Item = Either<A, B>>, A, B, const N: usize> Iterator for SplitRightIn<'_, I, A, B, N> {
+    type Item = B;
+
+    /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer.
+    /// Check ```overflowed``` to tell it apart from the end of the iterator.
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        stop_if_full(self.try_next())
+    }
+
+    #[inline(always)]
+    fn size_hint (&self) -> (usize, Option<usize>) {
+        let state = self.state.borrow();
+        let (_, mut max) = state.inner.size_hint();
+        let len = state.right.len + matches!(state.pending, Some(Right(_))) as usize;
+
+        if let Some(ref mut max) = max {
+            *max += len
+        }
+
+        (len, max)
+    }
+}
+
+impl<I, A, B, const N: usize> Clone for SplitRightIn<'_, I, A, B, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self { state: self.state }
+    }
+}
+
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "alloc")] {
+        use alloc::boxed::Box;
+
+        /// What split iterators do when a value has to be buffered for the other side, but it's buffer is already full
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        pub enum Overflow<A, B> {
+            /// Stop pulling values until the other side makes room. Meanwhile, ```try_next``` returns ```Err(BufferFull)```, ```next``` returns ```None``` and ```overflowed``` returns ```true```
+            Error,
+            /// Discard the oldest buffered value to make room for the new one
+            DropOldest,
+            /// Wait until the other side makes room. Only supported by thread-safe split iterators
+            Block,
+            /// Hand the value over to the callback instead of buffering it
+            Spill(Box<dyn FnMut(Either<A, B>) + Send>)
+        }
+
+        impl<A, B> core::fmt::Debug for Overflow<A, B> {
+            fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    Self::Error => f.write_str("Error"),
+                    Self::DropOldest => f.write_str("DropOldest"),
+                    Self::Block => f.write_str("Block"),
+                    Self::Spill(_) => f.write_str("Spill(..)")
+                }
+            }
+        }
+
+        #[derive(Debug)]
+        struct SplitState<I, A, B> {
+            inner: I,
+            /// Value that didn't fit in it's side's buffer
+            pending: Option<Either<A, B>>,
+            capacity: usize,
+            overflow: Overflow<A, B>
+        }
+
+        /// Pushes ```value``` into ```buffer```, applying the overflow policy if it's full.
+        /// Returns the value back if it has to wait for the other side.
+        #[inline]
+        fn push_bounded<T, A, B> (buffer: &mut VecDeque<T>, value: T, capacity: usize, overflow: &mut Overflow<A, B>, wrap: fn(T) -> Either<A, B>) -> Result<(), T> {
+            if buffer.len() < capacity {
+                buffer.push_back(value);
+                return Ok(());
+            }
+
+            match overflow {
+                Overflow::Error | Overflow::Block => return Err(value),
+                Overflow::DropOldest => {
+                    buffer.pop_front();
+                    buffer.push_back(value);
+                },
+                Overflow::Spill(f) => f(wrap(value))
+            }
+
+            Ok(())
+        }
+
+        /// Iterator that returns the left values of an iterator of Either values.
+        /// This iterator is **not thread-safe**, but maintains item order and has less overhead than ```SplitLeftArc```.
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        #[derive(Debug)]
+        pub struct SplitLeft<I, A, B> {
+            inner: Rc<RefCell<SplitState<I, A, B>>>,
+            this: Rc<RefCell<VecDeque<A>>>,
+            other: Rc<RefCell<VecDeque<B>>>
+        }
+
+        impl<I, A, B> SplitLeft<I, A, B> {
+            /// Returns the number of values currently buffered for this side
+            #[inline(always)]
+            pub fn buffered (&self) -> usize {
+                self.this.borrow().len()
+            }
+
+            /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+            #[inline(always)]
+            pub fn overflowed (&self) -> bool {
+                let state = self.inner.borrow();
+                matches!(state.pending, Some(Right(_))) && self.other.borrow().len() >= state.capacity
+            }
+
+            /// Returns the maximum number of values buffered for each side
+            #[inline(always)]
+            pub fn capacity (&self) -> usize {
+                self.inner.borrow().capacity
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> SplitLeft<I, A, B> {
+            /// Returns the next left value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the right side's buffer
+            pub fn try_next (&mut self) -> Result<Option<A>, BufferFull> {
+                if let Some(next) = self.this.borrow_mut().pop_front() { 
+                    return Ok(Some(next)); 
+                }
+
+                let mut state = self.inner.borrow_mut();
+                let state = &mut *state;
+
+                match state.pending.take() {
+                    Some(Left(value)) => return Ok(Some(value)),
+                    Some(Right(value)) => if let Err(value) = push_bounded(&mut self.other.borrow_mut(), value, state.capacity, &mut state.overflow, Right) {
+                        state.pending = Some(Right(value));
+                        return Err(BufferFull);
+                    },
+                    None => {}
+                }
+
+                for next in state.inner.by_ref() {
+                    match next {
+                        Left(value) => return Ok(Some(value)),
+                        Right(value) => if let Err(value) = push_bounded(&mut self.other.borrow_mut(), value, state.capacity, &mut state.overflow, Right) {
+                            state.pending = Some(Right(value));
+                            return Err(BufferFull);
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitLeft<I, A, B> {
+            type Item = A;
+
+            /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer (see ```Overflow::Error```).
+            /// Check ```overflowed``` to tell it apart from the end of the iterator.
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                stop_if_full(self.try_next())
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = self.inner.borrow();
+                let (_, mut max) = state.inner.size_hint();
+                let len = self.this.borrow().len() + matches!(state.pending, Some(Left(_))) as usize;
+
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+                
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitLeft<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self { inner: self.inner.clone(), this: self.this.clone(), other: self.other.clone() }
+            }
+        }
+
+        /// Iterator that returns the right values of an iterator of Either values.
+        /// This iterator is **not thread-safe**, but maintains item order and has less overhead than ```SplitRightArc```.
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        #[derive(Debug)]
+        pub struct SplitRight<I, A, B> {
+            inner: Rc<RefCell<SplitState<I, A, B>>>,
+            this: Rc<RefCell<VecDeque<B>>>,
+            other: Rc<RefCell<VecDeque<A>>>
+        }
+
+        impl<I, A, B> SplitRight<I, A, B> {
+            /// Returns the number of values currently buffered for this side
+            #[inline(always)]
+            pub fn buffered (&self) -> usize {
+                self.this.borrow().len()
+            }
+
+            /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+            #[inline(always)]
+            pub fn overflowed (&self) -> bool {
+                let state = self.inner.borrow();
+                matches!(state.pending, Some(Left(_))) && self.other.borrow().len() >= state.capacity
+            }
+
+            /// Returns the maximum number of values buffered for each side
+            #[inline(always)]
+            pub fn capacity (&self) -> usize {
+                self.inner.borrow().capacity
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> SplitRight<I, A, B> {
+            /// Returns the next right value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the left side's buffer
+            pub fn try_next (&mut self) -> Result<Option<B>, BufferFull> {
+                if let Some(next) = self.this.borrow_mut().pop_front() { 
+                    return Ok(Some(next)); 
+                }
+
+                let mut state = self.inner.borrow_mut();
+                let state = &mut *state;
+
+                match state.pending.take() {
+                    Some(Right(value)) => return Ok(Some(value)),
+                    Some(Left(value)) => if let Err(value) = push_bounded(&mut self.other.borrow_mut(), value, state.capacity, &mut state.overflow, Left) {
+                        state.pending = Some(Left(value));
+                        return Err(BufferFull);
+                    },
+                    None => {}
+                }
+
+                for next in state.inner.by_ref() {
+                    match next {
+                        Right(value) => return Ok(Some(value)),
+                        Left(value) => if let Err(value) = push_bounded(&mut self.other.borrow_mut(), value, state.capacity, &mut state.overflow, Left) {
+                            state.pending = Some(Left(value));
+                            return Err(BufferFull);
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitRight<I, A, B> {
+            type Item = B;
+
+            /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer (see ```Overflow::Error```).
+            /// Check ```overflowed``` to tell it apart from the end of the iterator.
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                stop_if_full(self.try_next())
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = self.inner.borrow();
+                let (_, mut max) = state.inner.size_hint();
+                let len = self.this.borrow().len() + matches!(state.pending, Some(Right(_))) as usize;
+
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+                
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitRight<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self { inner: self.inner.clone(), this: self.this.clone(), other: self.other.clone() }
+            }
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Buffer of one side of a thread-safe split iterator
+        #[derive(Debug)]
+        struct SplitBuffer<T> {
+            queue: Mutex<VecDeque<T>>,
+            not_full: Condvar,
+            /// Live iterators of this side. Once it reaches zero, nobody will read the buffer again
+            readers: AtomicUsize
+        }
+
+        impl<T> SplitBuffer<T> {
+            #[inline(always)]
+            fn new (capacity: usize) -> Self {
+                Self { queue: Mutex::new(VecDeque::with_capacity(capacity)), not_full: Condvar::new(), readers: AtomicUsize::new(1) }
+            }
+
+            #[inline]
+            fn pop (&self) -> Option<T> {
+                let next = lock_deep(&self.queue).pop_front();
+                if next.is_some() {
+                    self.not_full.notify_all();
+                }
+                next
+            }
+
+            #[inline]
+            fn push<A, B> (&self, value: T, capacity: usize, overflow: &mut Overflow<A, B>, wrap: fn(T) -> Either<A, B>) -> Result<(), T> {
+                let mut queue = lock_deep(&self.queue);
+                if self.readers.load(Ordering::Acquire) == 0 {
+                    return Ok(());
+                }
+
+                push_bounded(&mut queue, value, capacity, overflow, wrap)
+            }
+
+            /// Waits until the buffer has room for another value, or nobody is left to read it
+            #[inline]
+            fn wait (&self, capacity: usize) {
+                let queue = lock_deep(&self.queue);
+                drop(self.not_full.wait_while(queue, |queue| queue.len() >= capacity && self.readers.load(Ordering::Acquire) > 0));
+            }
+
+            #[inline(always)]
+            fn attach (&self) {
+                self.readers.fetch_add(1, Ordering::AcqRel);
+            }
+
+            #[inline]
+            fn detach (&self) {
+                let queue = lock_deep(&self.queue);
+                self.readers.fetch_sub(1, Ordering::AcqRel);
+                drop(queue);
+                self.not_full.notify_all();
+            }
+        }
+
+        /// Iterator that returns the left values of an iterator of Either values.
+        /// This iterator is **thread-safe** and maintains item order, but adds more overhead than ```SplitLeft```.
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        #[derive(Debug)]
+        pub struct SplitLeftArc<I, A, B> {
+            inner: Arc<Mutex<SplitState<I, A, B>>>,
+            this: Arc<SplitBuffer<A>>,
+            other: Arc<SplitBuffer<B>>
+        }
+
+        impl<I, A, B> SplitLeftArc<I, A, B> {
+            /// Returns the number of values currently buffered for this side
+            #[inline(always)]
+            pub fn buffered (&self) -> usize {
+                lock_deep(&self.this.queue).len()
+            }
+
+            /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+            #[inline(always)]
+            pub fn overflowed (&self) -> bool {
+                let state = lock_deep(&self.inner);
+                matches!(state.pending, Some(Right(_))) && lock_deep(&self.other.queue).len() >= state.capacity
+            }
+
+            /// Returns the maximum number of values buffered for each side
+            #[inline(always)]
+            pub fn capacity (&self) -> usize {
+                lock_deep(&self.inner).capacity
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> SplitLeftArc<I, A, B> {
+            /// Returns the next left value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the right side's buffer
+            pub fn try_next (&mut self) -> Result<Option<A>, BufferFull> {
+                loop {
+                    if let Some(next) = self.this.pop() {
+                        return Ok(Some(next));
+                    }
+
+                    let mut state = lock_deep(&self.inner);
+                    match self.pull(&mut state) {
+                        // wait without holding the lock, so the other side can keep going
+                        Err(BufferFull) if matches!(state.overflow, Overflow::Block) => {
+                            let capacity = state.capacity;
+                            drop(state);
+                            self.other.wait(capacity);
+                        },
+                        result => return result
+                    }
+                }
+            }
+
+            fn pull (&self, state: &mut SplitState<I, A, B>) -> Result<Option<A>, BufferFull> {
+                // values are only pushed while the lock is held, so anything buffered by now is older than what's left to pull
+                if let Some(next) = self.this.pop() {
+                    return Ok(Some(next));
+                }
+
+                match state.pending.take() {
+                    Some(Left(value)) => return Ok(Some(value)),
+                    Some(Right(value)) => if let Err(value) = self.other.push(value, state.capacity, &mut state.overflow, Right) {
+                        state.pending = Some(Right(value));
+                        return Err(BufferFull);
+                    },
+                    None => {}
+                }
+
+                for next in state.inner.by_ref() {
+                    match next {
+                        Left(value) => return Ok(Some(value)),
+                        Right(value) => if let Err(value) = self.other.push(value, state.capacity, &mut state.overflow, Right) {
+                            state.pending = Some(Right(value));
+                            return Err(BufferFull);
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitLeftArc<I, A, B> {
+            type Item = A;
+
+            /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer (see ```Overflow::Error```).
+            /// Check ```overflowed``` to tell it apart from the end of the iterator.
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                stop_if_full(self.try_next())
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = lock_deep(&self.inner);
+                let (_, mut max) = state.inner.size_hint();
+                let pending = matches!(state.pending, Some(Left(_))) as usize;
+                drop(state);
+
+                let len = self.buffered() + pending;
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+                
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitLeftArc<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                self.this.attach();
+                Self { inner: self.inner.clone(), this: self.this.clone(), other: self.other.clone() }
+            }
+        }
+
+        impl<I, A, B> Drop for SplitLeftArc<I, A, B> {
+            #[inline(always)]
+            fn drop (&mut self) {
+                self.this.detach()
+            }
+        }
+
+        /// Iterator that returns the right values of an iterator of Either values.
+        /// This iterator is **thread-safe** and maintains item order, but adds more overhead than ```SplitRight```.
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        #[derive(Debug)]
+        pub struct SplitRightArc<I, A, B> {
+            inner: Arc<Mutex<SplitState<I, A, B>>>,
+            this: Arc<SplitBuffer<B>>,
+            other: Arc<SplitBuffer<A>>
+        }
+
+        impl<I, A, B> SplitRightArc<I, A, B> {
+            /// Returns the number of values currently buffered for this side
+            #[inline(always)]
+            pub fn buffered (&self) -> usize {
+                lock_deep(&self.this.queue).len()
+            }
+
+            /// Returns ```true``` if this side can't go on until the other side makes room in it's buffer
+            #[inline(always)]
+            pub fn overflowed (&self) -> bool {
+                let state = lock_deep(&self.inner);
+                matches!(state.pending, Some(Left(_))) && lock_deep(&self.other.queue).len() >= state.capacity
+            }
+
+            /// Returns the maximum number of values buffered for each side
+            #[inline(always)]
+            pub fn capacity (&self) -> usize {
+                lock_deep(&self.inner).capacity
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> SplitRightArc<I, A, B> {
+            /// Returns the next right value, or ```Err(BufferFull)``` if the next value can't be reached without overflowing the left side's buffer
+            pub fn try_next (&mut self) -> Result<Option<B>, BufferFull> {
+                loop {
+                    if let Some(next) = self.this.pop() {
+                        return Ok(Some(next));
+                    }
+
+                    let mut state = lock_deep(&self.inner);
+                    match self.pull(&mut state) {
+                        // wait without holding the lock, so the other side can keep going
+                        Err(BufferFull) if matches!(state.overflow, Overflow::Block) => {
+                            let capacity = state.capacity;
+                            drop(state);
+                            self.other.wait(capacity);
+                        },
+                        result => return result
+                    }
+                }
+            }
+
+            fn pull (&self, state: &mut SplitState<I, A, B>) -> Result<Option<B>, BufferFull> {
+                // values are only pushed while the lock is held, so anything buffered by now is older than what's left to pull
+                if let Some(next) = self.this.pop() {
+                    return Ok(Some(next));
+                }
+
+                match state.pending.take() {
+                    Some(Right(value)) => return Ok(Some(value)),
+                    Some(Left(value)) => if let Err(value) = self.other.push(value, state.capacity, &mut state.overflow, Left) {
+                        state.pending = Some(Left(value));
+                        return Err(BufferFull);
+                    },
+                    None => {}
+                }
+
+                for next in state.inner.by_ref() {
+                    match next {
+                        Right(value) => return Ok(Some(value)),
+                        Left(value) => if let Err(value) = self.other.push(value, state.capacity, &mut state.overflow, Left) {
+                            state.pending = Some(Left(value));
+                            return Err(BufferFull);
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitRightArc<I, A, B> {
+            type Item = B;
+
+            /// Returns ```None``` early if the next value can't be reached without overflowing the other side's buffer (see ```Overflow::Error```).
+            /// Check ```overflowed``` to tell it apart from the end of the iterator.
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                stop_if_full(self.try_next())
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = lock_deep(&self.inner);
+                let (_, mut max) = state.inner.size_hint();
+                let pending = matches!(state.pending, Some(Right(_))) as usize;
+                drop(state);
+
+                let len = self.buffered() + pending;
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+                
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitRightArc<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                self.this.attach();
+                Self { inner: self.inner.clone(), this: self.this.clone(), other: self.other.clone() }
+            }
+        }
+
+        impl<I, A, B> Drop for SplitRightArc<I, A, B> {
+            #[inline(always)]
+            fn drop (&mut self) {
+                self.this.detach()
+            }
+        }
+
+        #[derive(Debug)]
+        struct SplitSyncState<I, A, B> {
+            inner: I,
+            left: VecDeque<A>,
+            right: VecDeque<B>
+        }
+
+        /// Iterator that returns the left values of an iterator of Either values.
+        /// This iterator is **thread-safe** and maintains item order, but every call to ```next``` locks both sides.
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        #[derive(Debug)]
+        pub struct SplitLeftSync<I, A, B> {
+            inner: Arc<Mutex<SplitSyncState<I, A, B>>>
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitLeftSync<I, A, B> {
+            type Item = A;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut state = lock_deep(&self.inner);
+                if let Some(next) = state.left.pop_front() {
+                    return Some(next);
+                }
+
+                while let Some(next) = state.inner.next() {
+                    match next {
+                        Left(value) => return Some(value),
+                        Right(value) => state.right.push_back(value)
+                    }
+                }
+
+                None
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = lock_deep(&self.inner);
+                let (_, mut max) = state.inner.size_hint();
+                let len = state.left.len();
+
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitLeftSync<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self { inner: self.inner.clone() }
+            }
+        }
+
+        /// Iterator that returns the right values of an iterator of Either values.
+        /// This iterator is **thread-safe** and maintains item order, but every call to ```next``` locks both sides.
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        #[derive(Debug)]
+        pub struct SplitRightSync<I, A, B> {
+            inner: Arc<Mutex<SplitSyncState<I, A, B>>>
+        }
+
+        impl<I: Iterator<Item = Either<A, B>>, A, B> Iterator for SplitRightSync<I, A, B> {
+            type Item = B;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut state = lock_deep(&self.inner);
+                if let Some(next) = state.right.pop_front() {
+                    return Some(next);
+                }
+
+                while let Some(next) = state.inner.next() {
+                    match next {
+                        Right(value) => return Some(value),
+                        Left(value) => state.left.push_back(value)
+                    }
+                }
+
+                None
+            }
+
+            #[inline(always)]
+            fn size_hint (&self) -> (usize, Option<usize>) {
+                let state = lock_deep(&self.inner);
+                let (_, mut max) = state.inner.size_hint();
+                let len = state.right.len();
+
+                if let Some(ref mut max) = max {
+                    *max += len
+                }
+
+                (len, max)
+            }
+        }
+
+        impl<I, A, B> Clone for SplitRightSync<I, A, B> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self { inner: self.inner.clone() }
+            }
+        }
+
+        #[inline(always)]
+        fn lock_deep<T> (mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+            match mutex.lock() {
+                Ok(guard) => guard,
+                Err(poisoned) => poisoned.into_inner()
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+use ::rayon::iter::{IndexedParallelIterator, ParallelExtend, ParallelIterator};
+use ::rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use crate::prelude::*;
+use super::LRIter;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<A: ParallelIterator, B: ParallelIterator> ParallelIterator for Either<A, B> {
+    type Item = Either<A::Item, B::Item>;
+
+    #[inline(always)]
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>> (self, consumer: C) -> C::Result {
+        match self {
+            Left(x) => x.map(Left).drive_unindexed(consumer),
+            Right(x) => x.map(Right).drive_unindexed(consumer)
+        }
+    }
+
+    #[inline(always)]
+    fn opt_len(&self) -> Option<usize> {
+        match self {
+            Left(x) => x.opt_len(),
+            Right(x) => x.opt_len()
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<A: IndexedParallelIterator, B: IndexedParallelIterator> IndexedParallelIterator for Either<A, B> {
+    #[inline(always)]
+    fn drive<C: Consumer<Self::Item>> (self, consumer: C) -> C::Result {
+        match self {
+            Left(x) => x.map(Left).drive(consumer),
+            Right(x) => x.map(Right).drive(consumer)
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match self {
+            Left(x) => x.len(),
+            Right(x) => x.len()
+        }
+    }
+
+    #[inline(always)]
+    fn with_producer<CB: ProducerCallback<Self::Item>> (self, callback: CB) -> CB::Output {
+        match self {
+            Left(x) => x.map(Left).with_producer(callback),
+            Right(x) => x.map(Right).with_producer(callback)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T: Send, A: ParallelIterator<Item = T>, B: ParallelIterator<Item = T>> ParallelIterator for LRIter<A, B> {
+    type Item = T;
+
+    #[inline(always)]
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>> (self, consumer: C) -> C::Result {
+        match self.0 {
+            Left(x) => x.drive_unindexed(consumer),
+            Right(x) => x.drive_unindexed(consumer)
+        }
+    }
+
+    #[inline(always)]
+    fn opt_len(&self) -> Option<usize> {
+        match &self.0 {
+            Left(x) => x.opt_len(),
+            Right(x) => x.opt_len()
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T: Send, A: IndexedParallelIterator<Item = T>, B: IndexedParallelIterator<Item = T>> IndexedParallelIterator for LRIter<A, B> {
+    #[inline(always)]
+    fn drive<C: Consumer<Self::Item>> (self, consumer: C) -> C::Result {
+        match self.0 {
+            Left(x) => x.drive(consumer),
+            Right(x) => x.drive(consumer)
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match &self.0 {
+            Left(x) => x.len(),
+            Right(x) => x.len()
+        }
+    }
+
+    #[inline(always)]
+    fn with_producer<CB: ProducerCallback<Self::Item>> (self, callback: CB) -> CB::Output {
+        match self.0 {
+            Left(x) => x.with_producer(callback),
+            Right(x) => x.with_producer(callback)
+        }
+    }
+}
+
+/// A parallel iterator with ```Either``` items
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub trait ParallelEitherIter<A: Send, B: Send>: ParallelIterator<Item = Either<A, B>> {
+    /// Collects the left and right values into two seperate collections, in parallel
+    #[inline]
+    fn par_partition_either<CA: Default + Send + ParallelExtend<A>, CB: Default + Send + ParallelExtend<B>> (self) -> (CA, CB) {
+        self.partition_map(|x| match x {
+            Left(x) => ::rayon::iter::Either::Left(x),
+            Right(x) => ::rayon::iter::Either::Right(x)
+        })
+    }
+}
+
+impl<I: ParallelIterator<Item = Either<A, B>>, A: Send, B: Send> ParallelEitherIter<A, B> for I {}
@@ -3,8 +3,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[cfg(all(feature = "alloc", feature = "std"))]
-compile_error!("`alloc` and `std` features cannot be enabled simultaneously");
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod lr;
 pub use lr::*;
@@ -13,13 +13,40 @@ mod refr;
 use pin_project::pin_project;
 pub use refr::*;
 
+mod nary;
+pub use nary::*;
+
+mod these;
+pub use these::*;
+
+mod validated;
+pub use validated::*;
+
+mod transpose;
+
+mod nest;
+pub use nest::*;
+
+mod factor;
+
 /// Iterator extensions
 pub mod iter;
 
+/// Traits for types with two mappable sides
+pub mod bifunctor;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        /// I/O implementations
+        mod io;
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "serialize")] {
-        mod serde;
-        pub use self::serde::*;
+        /// Serde extensions
+        #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+        pub mod serde;
         use ::serde::{Serialize, Deserialize};
     }
 }
@@ -38,11 +65,26 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "derive")] {
+        #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+        pub use elor_derive::{delegate, auto_either};
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "async")] {
         /// Future/Stream extensions 
-        mod future;
-        pub use future::*;
+        #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+        pub mod future;
+        pub use future::{LRFuture, LRSink, EitherStream};
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "tokio")] {
+        /// Tokio I/O implementations
+        mod tokio;
     }
 }
 
@@ -121,8 +163,9 @@ impl<A,B> Either<A,B> {
     /// 
     /// fn main () {
     ///     let mut alpha : Either<String, Vec<u8>> = Left("hello world".to_string());
+    ///     let mut gamma = "hello world".to_string();
     ///     let beta : Either<&mut str, &mut [u8]> = alpha.as_deref_mut();
-    ///     assert_eq!(beta, Left("hello world"))
+    ///     assert_eq!(beta, Left(gamma.as_mut_str()))
     /// }
     /// ```
     #[inline(always)]
@@ -234,11 +277,14 @@ impl<A,B> Either<A,B> {
 
     /// Returns the right value, panicking if the value is on the left
     #[inline(always)]
-    pub fn unwrap_right (self) -> A {
-        self.expect_left("called `Either::unwrap_right()` on a `Left` value")
+    pub fn unwrap_right (self) -> B {
+        self.expect_right("called `Either::unwrap_right()` on a `Left` value")
     }
 
     /// Returns the left value without checking if the value is on the right
+    ///
+    /// # Safety
+    /// The value must be a `Left`
     #[inline(always)]
     pub unsafe fn unwrap_left_unchecked (self) -> A {
         match self {
@@ -248,6 +294,9 @@ impl<A,B> Either<A,B> {
     }
 
     /// Returns the right value without checking if the value is on the left
+    ///
+    /// # Safety
+    /// The value must be a `Right`
     #[inline(always)]
     pub unsafe fn unwrap_right_unchecked (self) -> B {
         match self {
@@ -458,10 +507,10 @@ impl<T> From<Option<T>> for Either<T,()> {
     }
 }
 
-impl<T> Into<Option<T>> for Either<T,()> {
+impl<T> From<Either<T,()>> for Option<T> {
     #[inline(always)]
-    fn into(self) -> Option<T> {
-        match self {
+    fn from(x: Either<T,()>) -> Self {
+        match x {
             Left(x) => Some(x),
             _ => None
         }
@@ -478,10 +527,10 @@ impl<T,E> From<Result<T,E>> for Either<T,E> {
     }
 }
 
-impl<T,E> Into<Result<T,E>> for Either<T,E> {
+impl<T,E> From<Either<T,E>> for Result<T,E> {
     #[inline(always)]
-    fn into(self) -> Result<T,E> {
-        match self {
+    fn from(x: Either<T,E>) -> Self {
+        match x {
             Left(x) => Ok(x),
             Right(e) => Err(e)
         }
@@ -0,0 +1,344 @@
+use core::{ops::{Deref, DerefMut}, fmt::Display, iter::FusedIterator};
+use crate::*;
+
+macro_rules! impl_map_each {
+    ($name:ident; [$($pre_var:ident $pre_ty:ident,)*];) => {};
+
+    ($name:ident; [$($pre_var:ident $pre_ty:ident,)*]; $var:ident $ty:ident $map:ident $(, $post_var:ident $post_ty:ident $post_map:ident)*) => {
+        #[inline(always)]
+        pub fn $map<T, Func: FnOnce($ty) -> T> (self, f: Func) -> $name<$($pre_ty,)* T $(,$post_ty)*> {
+            match self {
+                $(Self::$pre_var(x) => $name::$pre_var(x),)*
+                Self::$var(x) => $name::$var(f(x)),
+                $(Self::$post_var(x) => $name::$post_var(x),)*
+            }
+        }
+
+        impl_map_each!($name; [$($pre_var $pre_ty,)* $var $ty,]; $($post_var $post_ty $post_map),*);
+    };
+}
+
+macro_rules! impl_either {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $proj:ident, $x:ident => $nested:ty;
+        $(
+            $var:ident ($ty:ident => $out:ident, $f:ident) [$($nest:tt)*]:
+            $is:ident, $get:ident, $map:ident, $expect:ident, $unwrap:ident
+        );+
+    ) => {
+        $(#[$meta])*
+        #[pin_project(project = $proj)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+        #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serialize", serde(untagged))]
+        pub enum $name<$($ty),+> {
+            $($var (#[pin] $ty)),+
+        }
+
+        impl<$($ty),+> $name<$($ty),+> {
+            $(
+                #[doc = concat!("Returns `true` if the value is a `", stringify!($var), "`")]
+                #[inline(always)]
+                pub const fn $is (&self) -> bool {
+                    matches!(self, Self::$var(_))
+                }
+            )+
+
+            /// Returns a new value with a reference to the value inside
+            #[inline(always)]
+            pub const fn as_ref (&self) -> $name<$(&$ty),+> {
+                match self {
+                    $(Self::$var(x) => $name::$var(x)),+
+                }
+            }
+
+            /// Returns a new value with a mutable reference to the value inside
+            #[inline(always)]
+            pub fn as_mut (&mut self) -> $name<$(&mut $ty),+> {
+                match self {
+                    $(Self::$var(x) => $name::$var(x)),+
+                }
+            }
+
+            /// Returns a new value with a dereference of the value inside
+            #[inline(always)]
+            pub fn as_deref (&self) -> $name<$(&<$ty as Deref>::Target),+> where $($ty: Deref),+ {
+                match self {
+                    $(Self::$var(x) => $name::$var(x.deref())),+
+                }
+            }
+
+            /// Returns a new value with a mutable dereference of the value inside
+            #[inline(always)]
+            pub fn as_deref_mut (&mut self) -> $name<$(&mut <$ty as Deref>::Target),+> where $($ty: DerefMut),+ {
+                match self {
+                    $(Self::$var(x) => $name::$var(x.deref_mut())),+
+                }
+            }
+
+            /// Returns a new value with a clone of the value inside
+            #[inline(always)]
+            pub fn cloned (&self) -> Self where $($ty: Clone),+ {
+                self.clone()
+            }
+
+            /// Returns a new value with a copy of the value inside
+            #[inline(always)]
+            pub fn copied (&self) -> Self where $($ty: Copy),+ {
+                *self
+            }
+
+            $(
+                #[doc = concat!("Returns the `", stringify!($var), "` value, `None` otherwise")]
+                #[inline(always)]
+                pub fn $get (self) -> Option<$ty> {
+                    match self {
+                        Self::$var(x) => Some(x),
+                        #[allow(unreachable_patterns)]
+                        _ => None
+                    }
+                }
+
+                #[doc = concat!("Returns the `", stringify!($var), "` value, panicking with a custom message otherwise")]
+                #[inline(always)]
+                pub fn $expect (self, msg: &str) -> $ty {
+                    match self {
+                        Self::$var(x) => x,
+                        #[allow(unreachable_patterns)]
+                        _ => panic!("{msg}")
+                    }
+                }
+
+                #[doc = concat!("Returns the `", stringify!($var), "` value, panicking otherwise")]
+                #[inline(always)]
+                pub fn $unwrap (self) -> $ty {
+                    self.$expect(concat!("called `", stringify!($name), "::", stringify!($unwrap), "()` on a non-`", stringify!($var), "` value"))
+                }
+            )+
+
+            /// Maps each possible value with it's respective function
+            #[allow(clippy::too_many_arguments)]
+            #[inline(always)]
+            pub fn map<$($out,)+ $($f: FnOnce($ty) -> $out),+> (self, $($get: $f),+) -> $name<$($out),+> {
+                match self {
+                    $(Self::$var(x) => $name::$var($get(x))),+
+                }
+            }
+
+            impl_map_each!($name; []; $($var $ty $map),+);
+
+            /// Folds each possible value into a single type with it's respective function
+            #[allow(clippy::too_many_arguments)]
+            #[inline(always)]
+            pub fn fold<T, $($f: FnOnce($ty) -> T),+> (self, $($get: $f),+) -> T {
+                match self {
+                    $(Self::$var(x) => $get(x)),+
+                }
+            }
+        }
+
+        impl<$($ty),+> From<$name<$($ty),+>> for $nested {
+            #[inline(always)]
+            fn from(x: $name<$($ty),+>) -> Self {
+                match x {
+                    $($name::$var($x) => $($nest)*),+
+                }
+            }
+        }
+
+        impl<$($ty),+> From<$nested> for $name<$($ty),+> {
+            #[inline(always)]
+            fn from(x: $nested) -> Self {
+                match x {
+                    $($($nest)* => Self::$var($x)),+
+                }
+            }
+        }
+
+        impl<T: ?Sized, $($ty: Deref<Target = T>),+> Deref for $name<$($ty),+> {
+            type Target = T;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                match self {
+                    $(Self::$var(x) => x.deref()),+
+                }
+            }
+        }
+
+        impl<T: ?Sized, $($ty: DerefMut<Target = T>),+> DerefMut for $name<$($ty),+> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                match self {
+                    $(Self::$var(x) => x.deref_mut()),+
+                }
+            }
+        }
+
+        impl<T: ?Sized, $($ty: AsRef<T>),+> AsRef<T> for $name<$($ty),+> {
+            #[inline(always)]
+            fn as_ref(&self) -> &T {
+                match self {
+                    $(Self::$var(x) => x.as_ref()),+
+                }
+            }
+        }
+
+        impl<T: ?Sized, $($ty: AsMut<T>),+> AsMut<T> for $name<$($ty),+> {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut T {
+                match self {
+                    $(Self::$var(x) => x.as_mut()),+
+                }
+            }
+        }
+
+        impl<$($ty: Display),+> Display for $name<$($ty),+> {
+            #[inline(always)]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(Self::$var(x) => x.fmt(f)),+
+                }
+            }
+        }
+
+        impl<$($ty: Iterator),+> Iterator for $name<$($ty),+> {
+            type Item = $name<$($ty::Item),+>;
+
+            #[inline(always)]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self {
+                    $(Self::$var(x) => x.next().map($name::$var)),+
+                }
+            }
+
+            #[inline(always)]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    $(Self::$var(x) => x.size_hint()),+
+                }
+            }
+        }
+
+        impl<$($ty: ExactSizeIterator),+> ExactSizeIterator for $name<$($ty),+> {
+            #[inline(always)]
+            fn len(&self) -> usize {
+                match self {
+                    $(Self::$var(x) => x.len()),+
+                }
+            }
+        }
+
+        impl<$($ty: DoubleEndedIterator),+> DoubleEndedIterator for $name<$($ty),+> {
+            #[inline(always)]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match self {
+                    $(Self::$var(x) => x.next_back().map($name::$var)),+
+                }
+            }
+        }
+
+        impl<$($ty: FusedIterator),+> FusedIterator for $name<$($ty),+> {}
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+        #[cfg(feature = "async")]
+        impl<$($ty: futures::Future),+> futures::Future for $name<$($ty),+> {
+            type Output = $name<$($ty::Output),+>;
+
+            #[inline(always)]
+            fn poll(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+                match self.project() {
+                    $($proj::$var(x) => x.poll(cx).map($name::$var)),+
+                }
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+        #[cfg(feature = "async")]
+        impl<$($ty: futures::Stream),+> futures::Stream for $name<$($ty),+> {
+            type Item = $name<$($ty::Item),+>;
+
+            #[inline(always)]
+            fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+                match self.project() {
+                    $($proj::$var(x) => x.poll_next(cx).map(|x| x.map($name::$var))),+
+                }
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "macro")))]
+        #[cfg(feature = "macro")]
+        impl<$($ty: quote::ToTokens),+> quote::ToTokens for $name<$($ty),+> {
+            #[inline(always)]
+            fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+                match self {
+                    $(Self::$var(x) => x.to_tokens(tokens)),+
+                }
+            }
+        }
+    };
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of three types.
+    Either3, Either3Proj, x => Either<A, Either<B, C>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(x))]: is_third, third, map_third, expect_third, unwrap_third
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of four types.
+    Either4, Either4Proj, x => Either<A, Either<B, Either<C, D>>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(Left(x)))]: is_third, third, map_third, expect_third, unwrap_third;
+    Fourth (D => X4, F4) [Right(Right(Right(x)))]: is_fourth, fourth, map_fourth, expect_fourth, unwrap_fourth
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of five types.
+    Either5, Either5Proj, x => Either<A, Either<B, Either<C, Either<D, E>>>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(Left(x)))]: is_third, third, map_third, expect_third, unwrap_third;
+    Fourth (D => X4, F4) [Right(Right(Right(Left(x))))]: is_fourth, fourth, map_fourth, expect_fourth, unwrap_fourth;
+    Fifth (E => X5, F5) [Right(Right(Right(Right(x))))]: is_fifth, fifth, map_fifth, expect_fifth, unwrap_fifth
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of six types.
+    Either6, Either6Proj, x => Either<A, Either<B, Either<C, Either<D, Either<E, F>>>>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(Left(x)))]: is_third, third, map_third, expect_third, unwrap_third;
+    Fourth (D => X4, F4) [Right(Right(Right(Left(x))))]: is_fourth, fourth, map_fourth, expect_fourth, unwrap_fourth;
+    Fifth (E => X5, F5) [Right(Right(Right(Right(Left(x)))))]: is_fifth, fifth, map_fifth, expect_fifth, unwrap_fifth;
+    Sixth (F => X6, F6) [Right(Right(Right(Right(Right(x)))))]: is_sixth, sixth, map_sixth, expect_sixth, unwrap_sixth
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of seven types.
+    Either7, Either7Proj, x => Either<A, Either<B, Either<C, Either<D, Either<E, Either<F, G>>>>>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(Left(x)))]: is_third, third, map_third, expect_third, unwrap_third;
+    Fourth (D => X4, F4) [Right(Right(Right(Left(x))))]: is_fourth, fourth, map_fourth, expect_fourth, unwrap_fourth;
+    Fifth (E => X5, F5) [Right(Right(Right(Right(Left(x)))))]: is_fifth, fifth, map_fifth, expect_fifth, unwrap_fifth;
+    Sixth (F => X6, F6) [Right(Right(Right(Right(Right(Left(x))))))]: is_sixth, sixth, map_sixth, expect_sixth, unwrap_sixth;
+    Seventh (G => X7, F7) [Right(Right(Right(Right(Right(Right(x))))))]: is_seventh, seventh, map_seventh, expect_seventh, unwrap_seventh
+}
+
+impl_either! {
+    /// Generic data type that represents a value that's of one of eight types.
+    Either8, Either8Proj, x => Either<A, Either<B, Either<C, Either<D, Either<E, Either<F, Either<G, H>>>>>>>;
+    First (A => X1, F1) [Left(x)]: is_first, first, map_first, expect_first, unwrap_first;
+    Second (B => X2, F2) [Right(Left(x))]: is_second, second, map_second, expect_second, unwrap_second;
+    Third (C => X3, F3) [Right(Right(Left(x)))]: is_third, third, map_third, expect_third, unwrap_third;
+    Fourth (D => X4, F4) [Right(Right(Right(Left(x))))]: is_fourth, fourth, map_fourth, expect_fourth, unwrap_fourth;
+    Fifth (E => X5, F5) [Right(Right(Right(Right(Left(x)))))]: is_fifth, fifth, map_fifth, expect_fifth, unwrap_fifth;
+    Sixth (F => X6, F6) [Right(Right(Right(Right(Right(Left(x))))))]: is_sixth, sixth, map_sixth, expect_sixth, unwrap_sixth;
+    Seventh (G => X7, F7) [Right(Right(Right(Right(Right(Right(Left(x)))))))]: is_seventh, seventh, map_seventh, expect_seventh, unwrap_seventh;
+    Eighth (H => X8, F8) [Right(Right(Right(Right(Right(Right(Right(x)))))))]: is_eighth, eighth, map_eighth, expect_eighth, unwrap_eighth
+}
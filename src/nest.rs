@@ -0,0 +1,176 @@
+use crate::prelude::*;
+
+impl<A,B,C> Either<A,Either<B,C>> {
+    /// Converts an ```Either<A, Either<B, C>>``` into an ```Either<Either<A, B>, C>```
+    #[inline(always)]
+    pub fn assoc_left (self) -> Either<Either<A,B>,C> {
+        match self {
+            Left(x) => Left(Left(x)),
+            Right(Left(x)) => Left(Right(x)),
+            Right(Right(x)) => Right(x)
+        }
+    }
+}
+
+impl<A,B,C> Either<Either<A,B>,C> {
+    /// Converts an ```Either<Either<A, B>, C>``` into an ```Either<A, Either<B, C>>```
+    #[inline(always)]
+    pub fn assoc_right (self) -> Either<A,Either<B,C>> {
+        match self {
+            Left(Left(x)) => Left(x),
+            Left(Right(x)) => Right(Left(x)),
+            Right(x) => Right(Right(x))
+        }
+    }
+}
+
+impl<A,B> Either<A,B> {
+    /// Embeds the value into the left side of an ```Either<Either<A, B>, C>```
+    #[inline(always)]
+    pub fn inject_left<C> (self) -> Either<Either<A,B>,C> {
+        Left(self)
+    }
+
+    /// Embeds the value into the right side of an ```Either<C, Either<A, B>>```
+    #[inline(always)]
+    pub fn inject_right<C> (self) -> Either<C,Either<A,B>> {
+        Right(self)
+    }
+
+    /// Embeds the value into an ```Either<A, Either<C, B>>```, leaving room for a ```C``` between both sides
+    #[inline(always)]
+    pub fn inject_middle<C> (self) -> Either<A,Either<C,B>> {
+        match self {
+            Left(x) => Left(x),
+            Right(x) => Right(Right(x))
+        }
+    }
+}
+
+/// Types that [```Flatten```] keeps as they are, instead of flattening them like nested ```Either```s.
+/// Implement it for your own types to flatten ```Either```s containing them.
+///
+/// Only primitives and common ```core```/```alloc``` types implement it out of the box.
+/// Because of the orphan rule, types from other crates can't implement it outside of them,
+/// so wrap those in [```AsLeaf```] instead.
+pub trait Leaf {}
+
+/// Wrapper that implements [```Leaf```] for any type, for those that can't implement it themselves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[repr(transparent)]
+pub struct AsLeaf<T> (pub T);
+
+impl<T> AsLeaf<T> {
+    /// Returns the wrapped value
+    #[inline(always)]
+    pub fn into_inner (self) -> T {
+        self.0
+    }
+}
+
+impl<T> Leaf for AsLeaf<T> {}
+
+/// Canonicalizes arbitrarily nested ```Either```s into their right-nested form,
+/// so that ```Either<Either<A, B>, Either<C, D>>``` becomes ```Either<A, Either<B, Either<C, D>>>```.
+///
+/// Every type that isn't an ```Either``` must implement [```Leaf```], or be wrapped in [```AsLeaf```].
+pub trait Flatten {
+    type Output;
+
+    fn flatten_nested (self) -> Self::Output;
+}
+
+impl<T: Leaf> Flatten for T {
+    type Output = T;
+
+    #[inline(always)]
+    fn flatten_nested (self) -> T {
+        self
+    }
+}
+
+impl<A: Prepend<B::Output>, B: Flatten> Flatten for Either<A,B> {
+    type Output = A::Output;
+
+    #[inline(always)]
+    fn flatten_nested (self) -> Self::Output {
+        match self {
+            Left(x) => x.prepend(),
+            Right(x) => A::tail(x.flatten_nested())
+        }
+    }
+}
+
+/// Right-nested ```Either``` with the leaves of ```Self``` followed by ```T```
+#[doc(hidden)]
+pub trait Prepend<T> {
+    type Output;
+
+    fn prepend (self) -> Self::Output;
+    fn tail (tail: T) -> Self::Output;
+}
+
+impl<L: Leaf, T> Prepend<T> for L {
+    type Output = Either<L,T>;
+
+    #[inline(always)]
+    fn prepend (self) -> Either<L,T> {
+        Left(self)
+    }
+
+    #[inline(always)]
+    fn tail (tail: T) -> Either<L,T> {
+        Right(tail)
+    }
+}
+
+impl<A: Prepend<B::Output>, B: Prepend<T>, T> Prepend<T> for Either<A,B> {
+    type Output = A::Output;
+
+    #[inline(always)]
+    fn prepend (self) -> Self::Output {
+        match self {
+            Left(x) => x.prepend(),
+            Right(x) => A::tail(x.prepend())
+        }
+    }
+
+    #[inline(always)]
+    fn tail (tail: T) -> Self::Output {
+        A::tail(B::tail(tail))
+    }
+}
+
+macro_rules! impl_leaf {
+    ($(<$($gen:ident),+> $ty:ty),+ $(,)?) => {
+        $(impl<$($gen),+> Leaf for $ty {})+
+    };
+
+    ($($ty:ty),+ $(,)?) => {
+        $(impl Leaf for $ty {})+
+    };
+}
+
+impl_leaf! {
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, core::time::Duration
+}
+
+impl_leaf! {
+    <T> Option<T>, <T, E> Result<T, E>, <T> core::task::Poll<T>, <T> core::marker::PhantomData<T>,
+    <A> (A,), <A, B> (A, B), <A, B, C> (A, B, C), <A, B, C, D> (A, B, C, D),
+    <A, B> crate::These<A, B>, <E, T> crate::Validated<E, T>
+}
+
+impl<T: ?Sized> Leaf for &T {}
+impl<T: ?Sized> Leaf for &mut T {}
+impl<T, const N: usize> Leaf for [T; N] {}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "alloc")] {
+        impl_leaf! { alloc::string::String }
+        impl_leaf! { <T> alloc::vec::Vec<T>, <T> alloc::boxed::Box<T>, <T> alloc::rc::Rc<T>, <T> alloc::sync::Arc<T> }
+    }
+}
@@ -1,11 +1,7 @@
 use crate::prelude::*;
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "std")] {
-        use std::borrow::{Cow, ToOwned};
-        use std::boxed::Box;
-    } else if #[cfg(feature = "alloc")] {
-        extern crate alloc;
+    if #[cfg(feature = "alloc")] {
         use alloc::borrow::{Cow, ToOwned};
         use alloc::boxed::Box;
     }
@@ -18,14 +14,14 @@ impl<'a, T> Boo<'a, T> {
     #[inline(always)]
     pub fn as_inner_ref (&self) -> &T {
         match self {
-            Left(x) => *x,
+            Left(x) => x,
             Right(x) => x
         }
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
-#[cfg(any(feature = "std", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
 impl<A, B> Either<Box<A>, Box<B>> {
     /// Convert into a single boxed value
     #[inline(always)]
@@ -57,8 +53,8 @@ impl<T: ?Sized, A: AsMut<T>, B: AsMut<T>> AsMut<T> for Either<A, B> {
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
-#[cfg(any(feature = "std", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
 impl<'a, T: ToOwned> From<Cow<'a, T>> for Either<&'a T, <T as ToOwned>::Owned> {
     #[inline(always)]
     fn from(x: Cow<'a, T>) -> Self {
@@ -69,12 +65,12 @@ impl<'a, T: ToOwned> From<Cow<'a, T>> for Either<&'a T, <T as ToOwned>::Owned> {
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
-#[cfg(any(feature = "std", feature = "alloc"))]
-impl<'a, T: ToOwned> Into<Cow<'a, T>> for Either<&'a T, T::Owned> {
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<'a, T: ToOwned> From<Either<&'a T, T::Owned>> for Cow<'a, T> {
     #[inline(always)]
-    fn into(self) -> Cow<'a, T> {
-        match self {
+    fn from(x: Either<&'a T, T::Owned>) -> Self {
+        match x {
             Left(x) => Cow::Borrowed(x),
             Right(x) => Cow::Owned(x),
         }
@@ -1,5 +1,5 @@
 mod ser;
 mod de;
+mod tagged;
 
-pub use ser::*;
-pub use de::*;
\ No newline at end of file
+pub use tagged::*;
@@ -0,0 +1,78 @@
+use ::serde::{Serialize, Deserialize, Serializer, Deserializer};
+use crate::*;
+
+macro_rules! impl_tagged {
+    ($($(#[$docs:meta])* $name:ident => $(#[$meta:meta])*),+) => {
+        $(
+            $(#[$docs])*
+            ///
+            /// ```LeftRight``` values are ```Either```s too, so they work as they are. ```LRIter```s go through the ```lr_iter``` submodule instead.
+            pub mod $name {
+                use super::*;
+
+                #[derive(Serialize)]
+                #[serde(rename = "Either")]
+                $(#[$meta])*
+                enum TaggedRef<'a, A, B> {
+                    Left (&'a A),
+                    Right (&'a B)
+                }
+
+                #[derive(Deserialize)]
+                #[serde(rename = "Either")]
+                $(#[$meta])*
+                enum Tagged<A, B> {
+                    Left (A),
+                    Right (B)
+                }
+
+                #[inline]
+                pub fn serialize<A: Serialize, B: Serialize, S: Serializer> (value: &Either<A, B>, serializer: S) -> Result<S::Ok, S::Error> {
+                    match value {
+                        Left(x) => TaggedRef::<A, B>::Left(x),
+                        Right(x) => TaggedRef::<A, B>::Right(x)
+                    }.serialize(serializer)
+                }
+
+                #[inline]
+                pub fn deserialize<'de, A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de>> (deserializer: D) -> Result<Either<A, B>, D::Error> {
+                    match Tagged::deserialize(deserializer)? {
+                        Tagged::Left(x) => Ok(Left(x)),
+                        Tagged::Right(x) => Ok(Right(x))
+                    }
+                }
+
+                /// Same representation, for the ```Either``` inside an ```LRIter```
+                pub mod lr_iter {
+                    use super::*;
+                    use crate::iter::LRIter;
+
+                    #[inline(always)]
+                    pub fn serialize<A: Serialize, B: Serialize, S: Serializer> (value: &LRIter<A, B>, serializer: S) -> Result<S::Ok, S::Error> {
+                        super::serialize(&value.0, serializer)
+                    }
+
+                    #[inline(always)]
+                    pub fn deserialize<'de, A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de>> (deserializer: D) -> Result<LRIter<A, B>, D::Error> {
+                        super::deserialize(deserializer).map(LRIter::new)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_tagged! {
+    /// Serializes an ```Either``` as a single-entry map from the side to the value: `{"Left": 1}` or `{"Right": "one"}`.
+    /// Use it via `#[serde(with = "elor::serde::externally_tagged")]`.
+    externally_tagged =>,
+
+    /// Serializes an ```Either``` as a map with the side and the value on seperate entries: `{"tag": "Left", "content": 1}`.
+    /// Use it via `#[serde(with = "elor::serde::adjacently_tagged")]`.
+    adjacently_tagged => #[serde(tag = "tag", content = "content")],
+
+    /// Serializes an ```Either``` as the value itself with an extra `tag` entry for the side: `{"tag": "Left", "id": 1}`.
+    /// Only values that serialize as maps or structs are supported.
+    /// Use it via `#[serde(with = "elor::serde::internally_tagged")]`.
+    internally_tagged => #[serde(tag = "tag")]
+}
@@ -0,0 +1,192 @@
+use crate::prelude::*;
+
+#[cfg(feature = "serialize")]
+use ::serde::{Serialize, Deserialize};
+
+/// Generic data type that represents a value of one type, a value of another, or both of them at once.
+/// Unlike ```Either```, it can express the "left only / right only / both" results of merging two sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum These<A, B> {
+    Left (A),
+    Right (B),
+    Both (A, B)
+}
+
+impl<A, B> These<A, B> {
+    /// Creates a new ```These``` from two ```Option```s, returning ```None``` if both are empty
+    #[inline(always)]
+    pub fn from_options (left: Option<A>, right: Option<B>) -> Option<Self> {
+        match (left, right) {
+            (Some(x), Some(y)) => Some(Self::Both(x, y)),
+            (Some(x), None) => Some(Self::Left(x)),
+            (None, Some(y)) => Some(Self::Right(y)),
+            (None, None) => None
+        }
+    }
+
+    /// Returns `true` if the value is a `Left`
+    #[inline(always)]
+    pub const fn is_left (&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Returns `true` if the value is a `Right`
+    #[inline(always)]
+    pub const fn is_right (&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns `true` if the value is a `Both`
+    #[inline(always)]
+    pub const fn is_both (&self) -> bool {
+        matches!(self, Self::Both(_, _))
+    }
+
+    /// Returns `true` if there is a left value (`Left` or `Both`)
+    #[inline(always)]
+    pub const fn has_left (&self) -> bool {
+        !self.is_right()
+    }
+
+    /// Returns `true` if there is a right value (`Right` or `Both`)
+    #[inline(always)]
+    pub const fn has_right (&self) -> bool {
+        !self.is_left()
+    }
+
+    /// Returns a new `These` with references to the values inside
+    #[inline(always)]
+    pub const fn as_ref (&self) -> These<&A, &B> {
+        match self {
+            Self::Left(x) => These::Left(x),
+            Self::Right(y) => These::Right(y),
+            Self::Both(x, y) => These::Both(x, y)
+        }
+    }
+
+    /// Returns a new `These` with mutable references to the values inside
+    #[inline(always)]
+    pub fn as_mut (&mut self) -> These<&mut A, &mut B> {
+        match self {
+            Self::Left(x) => These::Left(x),
+            Self::Right(y) => These::Right(y),
+            Self::Both(x, y) => These::Both(x, y)
+        }
+    }
+
+    /// Converts a single ```These``` into two ```Option```
+    #[inline(always)]
+    pub fn unzip (self) -> (Option<A>, Option<B>) {
+        match self {
+            Self::Left(x) => (Some(x), None),
+            Self::Right(y) => (None, Some(y)),
+            Self::Both(x, y) => (Some(x), Some(y))
+        }
+    }
+
+    /// Returns the left value, if any
+    #[inline(always)]
+    pub fn left (self) -> Option<A> {
+        self.unzip().0
+    }
+
+    /// Returns the right value, if any
+    #[inline(always)]
+    pub fn right (self) -> Option<B> {
+        self.unzip().1
+    }
+
+    /// Returns both values if both are present
+    #[inline(always)]
+    pub fn both (self) -> Option<(A, B)> {
+        match self {
+            Self::Both(x, y) => Some((x, y)),
+            _ => None
+        }
+    }
+
+    /// Returns the values inside, filling the missing ones with the provided defaults
+    #[inline(always)]
+    pub fn or (self, left: A, right: B) -> (A, B) {
+        let (x, y) = self.unzip();
+        (x.unwrap_or(left), y.unwrap_or(right))
+    }
+
+    /// Returns the values inside, filling the missing ones with their default values
+    #[inline(always)]
+    pub fn or_default (self) -> (A, B) where A: Default, B: Default {
+        let (x, y) = self.unzip();
+        (x.unwrap_or_default(), y.unwrap_or_default())
+    }
+
+    #[inline(always)]
+    pub fn map<X, Y, F: FnOnce(A) -> X, G: FnOnce(B) -> Y> (self, f: F, g: G) -> These<X, Y> {
+        match self {
+            Self::Left(x) => These::Left(f(x)),
+            Self::Right(y) => These::Right(g(y)),
+            Self::Both(x, y) => These::Both(f(x), g(y))
+        }
+    }
+
+    #[inline(always)]
+    pub fn map_left<T, F: FnOnce(A) -> T> (self, f: F) -> These<T, B> {
+        self.map(f, |y| y)
+    }
+
+    #[inline(always)]
+    pub fn map_right<T, F: FnOnce(B) -> T> (self, f: F) -> These<A, T> {
+        self.map(|x| x, f)
+    }
+
+    /// Swaps the left and right values
+    #[inline(always)]
+    pub fn inverse (self) -> These<B, A> {
+        match self {
+            Self::Left(x) => These::Right(x),
+            Self::Right(y) => These::Left(y),
+            Self::Both(x, y) => These::Both(y, x)
+        }
+    }
+}
+
+impl<A, B> From<Either<A, B>> for These<A, B> {
+    #[inline(always)]
+    fn from(value: Either<A, B>) -> Self {
+        match value {
+            Left(x) => Self::Left(x),
+            Right(y) => Self::Right(y)
+        }
+    }
+}
+
+/// Fails with both values when the ```These``` is a ```Both```
+impl<A, B> TryFrom<These<A, B>> for Either<A, B> {
+    type Error = (A, B);
+
+    #[inline(always)]
+    fn try_from(value: These<A, B>) -> Result<Self, Self::Error> {
+        match value {
+            These::Left(x) => Ok(Left(x)),
+            These::Right(y) => Ok(Right(y)),
+            These::Both(x, y) => Err((x, y))
+        }
+    }
+}
+
+impl<A, B> From<These<A, B>> for (Option<A>, Option<B>) {
+    #[inline(always)]
+    fn from(value: These<A, B>) -> Self {
+        value.unzip()
+    }
+}
+
+/// Fails when both ```Option```s are empty
+impl<A, B> TryFrom<(Option<A>, Option<B>)> for These<A, B> {
+    type Error = ();
+
+    #[inline(always)]
+    fn try_from((left, right): (Option<A>, Option<B>)) -> Result<Self, Self::Error> {
+        Self::from_options(left, right).ok_or(())
+    }
+}
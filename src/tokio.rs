@@ -0,0 +1,96 @@
+use core::{pin::Pin, task::{Context, Poll}};
+use std::io::{self, IoSlice, SeekFrom};
+use ::tokio::io::{AsyncRead, AsyncWrite, AsyncBufRead, AsyncSeek, ReadBuf};
+use crate::{EitherProj, prelude::*};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<A: AsyncRead, B: AsyncRead> AsyncRead for Either<A, B> {
+    #[inline(always)]
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_read(cx, buf),
+            EitherProj::Right(x) => x.poll_read(cx, buf),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<A: AsyncWrite, B: AsyncWrite> AsyncWrite for Either<A, B> {
+    #[inline(always)]
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_write(cx, buf),
+            EitherProj::Right(x) => x.poll_write(cx, buf),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>]) -> Poll<io::Result<usize>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_write_vectored(cx, bufs),
+            EitherProj::Right(x) => x.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    #[inline(always)]
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Left(x) => x.is_write_vectored(),
+            Right(x) => x.is_write_vectored(),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_flush(cx),
+            EitherProj::Right(x) => x.poll_flush(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_shutdown(cx),
+            EitherProj::Right(x) => x.poll_shutdown(cx),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<A: AsyncBufRead, B: AsyncBufRead> AsyncBufRead for Either<A, B> {
+    #[inline(always)]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_fill_buf(cx),
+            EitherProj::Right(x) => x.poll_fill_buf(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.project() {
+            EitherProj::Left(x) => x.consume(amt),
+            EitherProj::Right(x) => x.consume(amt),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl<A: AsyncSeek, B: AsyncSeek> AsyncSeek for Either<A, B> {
+    #[inline(always)]
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        match self.project() {
+            EitherProj::Left(x) => x.start_seek(position),
+            EitherProj::Right(x) => x.start_seek(position),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.project() {
+            EitherProj::Left(x) => x.poll_complete(cx),
+            EitherProj::Right(x) => x.poll_complete(cx),
+        }
+    }
+}
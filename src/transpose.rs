@@ -0,0 +1,261 @@
+use core::{task::Poll, ops::ControlFlow};
+use crate::prelude::*;
+
+impl<A,B> Either<Option<A>,B> {
+    /// Converts an ```Either<Option<A>, B>``` into an ```Option<Either<A, B>>```, returning ```None``` for a ```Left(None)```
+    #[inline(always)]
+    pub fn transpose_left (self) -> Option<Either<A,B>> {
+        match self {
+            Left(Some(x)) => Some(Left(x)),
+            Left(None) => None,
+            Right(x) => Some(Right(x))
+        }
+    }
+
+    /// Inverse of ```transpose_left```, converting ```None``` into ```Left(None)```
+    #[inline(always)]
+    pub fn expand_left_option (option: Option<Either<A,B>>) -> Self {
+        match option {
+            Some(Left(x)) => Left(Some(x)),
+            Some(Right(x)) => Right(x),
+            None => Left(None)
+        }
+    }
+}
+
+impl<A,B> Either<A,Option<B>> {
+    /// Converts an ```Either<A, Option<B>>``` into an ```Option<Either<A, B>>```, returning ```None``` for a ```Right(None)```
+    #[inline(always)]
+    pub fn transpose_right (self) -> Option<Either<A,B>> {
+        match self {
+            Left(x) => Some(Left(x)),
+            Right(Some(x)) => Some(Right(x)),
+            Right(None) => None
+        }
+    }
+
+    /// Inverse of ```transpose_right```, converting ```None``` into ```Right(None)```
+    #[inline(always)]
+    pub fn expand_right_option (option: Option<Either<A,B>>) -> Self {
+        match option {
+            Some(Left(x)) => Left(x),
+            Some(Right(x)) => Right(Some(x)),
+            None => Right(None)
+        }
+    }
+}
+
+impl<A,B> Either<Option<A>,Option<B>> {
+    /// Converts an ```Either<Option<A>, Option<B>>``` into an ```Option<Either<A, B>>```.
+    /// The side of a ```None``` value is lost, so ```expand_option``` puts it back on the left side.
+    #[inline(always)]
+    pub fn transpose (self) -> Option<Either<A,B>> {
+        self.flatten_option()
+    }
+
+    /// Inverse of ```transpose```, converting ```None``` into ```Left(None)```
+    #[inline(always)]
+    pub fn expand_option (option: Option<Either<A,B>>) -> Self {
+        match option {
+            Some(Left(x)) => Left(Some(x)),
+            Some(Right(x)) => Right(Some(x)),
+            None => Left(None)
+        }
+    }
+}
+
+impl<A,B,E> Either<Result<A,E>,B> {
+    /// Converts an ```Either<Result<A, E>, B>``` into a ```Result<Either<A, B>, E>```
+    #[inline(always)]
+    pub fn transpose_left (self) -> Result<Either<A,B>,E> {
+        match self {
+            Left(Ok(x)) => Ok(Left(x)),
+            Left(Err(e)) => Err(e),
+            Right(x) => Ok(Right(x))
+        }
+    }
+
+    /// Inverse of ```transpose_left```, converting ```Err(e)``` into ```Left(Err(e))```
+    #[inline(always)]
+    pub fn expand_left_result (result: Result<Either<A,B>,E>) -> Self {
+        match result {
+            Ok(Left(x)) => Left(Ok(x)),
+            Ok(Right(x)) => Right(x),
+            Err(e) => Left(Err(e))
+        }
+    }
+}
+
+impl<A,B,E> Either<A,Result<B,E>> {
+    /// Converts an ```Either<A, Result<B, E>>``` into a ```Result<Either<A, B>, E>```
+    #[inline(always)]
+    pub fn transpose_right (self) -> Result<Either<A,B>,E> {
+        match self {
+            Left(x) => Ok(Left(x)),
+            Right(Ok(x)) => Ok(Right(x)),
+            Right(Err(e)) => Err(e)
+        }
+    }
+
+    /// Inverse of ```transpose_right```, converting ```Err(e)``` into ```Right(Err(e))```
+    #[inline(always)]
+    pub fn expand_right_result (result: Result<Either<A,B>,E>) -> Self {
+        match result {
+            Ok(Left(x)) => Left(x),
+            Ok(Right(x)) => Right(Ok(x)),
+            Err(e) => Right(Err(e))
+        }
+    }
+}
+
+impl<A,B,E> Either<Result<A,E>,Result<B,E>> {
+    /// Converts an ```Either<Result<A, E>, Result<B, E>>``` into a ```Result<Either<A, B>, E>```.
+    /// The side of an error is lost, so ```expand_shared_result``` puts it back on the left side. Use ```flatten_result``` to keep it.
+    #[inline(always)]
+    pub fn transpose (self) -> Result<Either<A,B>,E> {
+        self.flatten_result().map_err(Either::into_inner)
+    }
+
+    /// Inverse of ```transpose```, converting ```Err(e)``` into ```Left(Err(e))```
+    #[inline(always)]
+    pub fn expand_shared_result (result: Result<Either<A,B>,E>) -> Self {
+        match result {
+            Ok(Left(x)) => Left(Ok(x)),
+            Ok(Right(x)) => Right(Ok(x)),
+            Err(e) => Left(Err(e))
+        }
+    }
+}
+
+impl<A,B> Either<Poll<A>,B> {
+    /// Converts an ```Either<Poll<A>, B>``` into a ```Poll<Either<A, B>>```, returning ```Pending``` for a ```Left(Pending)```
+    #[inline(always)]
+    pub fn transpose_left (self) -> Poll<Either<A,B>> {
+        match self {
+            Left(Poll::Ready(x)) => Poll::Ready(Left(x)),
+            Left(Poll::Pending) => Poll::Pending,
+            Right(x) => Poll::Ready(Right(x))
+        }
+    }
+
+    /// Inverse of ```transpose_left```, converting ```Pending``` into ```Left(Pending)```
+    #[inline(always)]
+    pub fn expand_left_poll (poll: Poll<Either<A,B>>) -> Self {
+        match poll {
+            Poll::Ready(Left(x)) => Left(Poll::Ready(x)),
+            Poll::Ready(Right(x)) => Right(x),
+            Poll::Pending => Left(Poll::Pending)
+        }
+    }
+}
+
+impl<A,B> Either<A,Poll<B>> {
+    /// Converts an ```Either<A, Poll<B>>``` into a ```Poll<Either<A, B>>```, returning ```Pending``` for a ```Right(Pending)```
+    #[inline(always)]
+    pub fn transpose_right (self) -> Poll<Either<A,B>> {
+        match self {
+            Left(x) => Poll::Ready(Left(x)),
+            Right(Poll::Ready(x)) => Poll::Ready(Right(x)),
+            Right(Poll::Pending) => Poll::Pending
+        }
+    }
+
+    /// Inverse of ```transpose_right```, converting ```Pending``` into ```Right(Pending)```
+    #[inline(always)]
+    pub fn expand_right_poll (poll: Poll<Either<A,B>>) -> Self {
+        match poll {
+            Poll::Ready(Left(x)) => Left(x),
+            Poll::Ready(Right(x)) => Right(Poll::Ready(x)),
+            Poll::Pending => Right(Poll::Pending)
+        }
+    }
+}
+
+impl<A,B> Either<Poll<A>,Poll<B>> {
+    /// Converts an ```Either<Poll<A>, Poll<B>>``` into a ```Poll<Either<A, B>>```.
+    /// The side of a ```Pending``` value is lost, so ```expand_poll``` puts it back on the left side.
+    #[inline(always)]
+    pub fn transpose (self) -> Poll<Either<A,B>> {
+        match self {
+            Left(x) => x.map(Left),
+            Right(x) => x.map(Right)
+        }
+    }
+
+    /// Inverse of ```transpose```, converting ```Pending``` into ```Left(Pending)```
+    #[inline(always)]
+    pub fn expand_poll (poll: Poll<Either<A,B>>) -> Self {
+        match poll {
+            Poll::Ready(Left(x)) => Left(Poll::Ready(x)),
+            Poll::Ready(Right(x)) => Right(Poll::Ready(x)),
+            Poll::Pending => Left(Poll::Pending)
+        }
+    }
+}
+
+impl<A,B,C> Either<ControlFlow<C,A>,B> {
+    /// Converts an ```Either<ControlFlow<C, A>, B>``` into a ```ControlFlow<C, Either<A, B>>```
+    #[inline(always)]
+    pub fn transpose_left (self) -> ControlFlow<C,Either<A,B>> {
+        match self {
+            Left(ControlFlow::Continue(x)) => ControlFlow::Continue(Left(x)),
+            Left(ControlFlow::Break(c)) => ControlFlow::Break(c),
+            Right(x) => ControlFlow::Continue(Right(x))
+        }
+    }
+
+    /// Inverse of ```transpose_left```, converting ```Break(c)``` into ```Left(Break(c))```
+    #[inline(always)]
+    pub fn expand_left_control_flow (flow: ControlFlow<C,Either<A,B>>) -> Self {
+        match flow {
+            ControlFlow::Continue(Left(x)) => Left(ControlFlow::Continue(x)),
+            ControlFlow::Continue(Right(x)) => Right(x),
+            ControlFlow::Break(c) => Left(ControlFlow::Break(c))
+        }
+    }
+}
+
+impl<A,B,C> Either<A,ControlFlow<C,B>> {
+    /// Converts an ```Either<A, ControlFlow<C, B>>``` into a ```ControlFlow<C, Either<A, B>>```
+    #[inline(always)]
+    pub fn transpose_right (self) -> ControlFlow<C,Either<A,B>> {
+        match self {
+            Left(x) => ControlFlow::Continue(Left(x)),
+            Right(ControlFlow::Continue(x)) => ControlFlow::Continue(Right(x)),
+            Right(ControlFlow::Break(c)) => ControlFlow::Break(c)
+        }
+    }
+
+    /// Inverse of ```transpose_right```, converting ```Break(c)``` into ```Right(Break(c))```
+    #[inline(always)]
+    pub fn expand_right_control_flow (flow: ControlFlow<C,Either<A,B>>) -> Self {
+        match flow {
+            ControlFlow::Continue(Left(x)) => Left(x),
+            ControlFlow::Continue(Right(x)) => Right(ControlFlow::Continue(x)),
+            ControlFlow::Break(c) => Right(ControlFlow::Break(c))
+        }
+    }
+}
+
+impl<A,B,C> Either<ControlFlow<C,A>,ControlFlow<C,B>> {
+    /// Converts an ```Either<ControlFlow<C, A>, ControlFlow<C, B>>``` into a ```ControlFlow<C, Either<A, B>>```.
+    /// The side of a ```Break``` value is lost, so ```expand_control_flow``` puts it back on the left side.
+    #[inline(always)]
+    pub fn transpose (self) -> ControlFlow<C,Either<A,B>> {
+        match self {
+            Left(ControlFlow::Continue(x)) => ControlFlow::Continue(Left(x)),
+            Right(ControlFlow::Continue(x)) => ControlFlow::Continue(Right(x)),
+            Left(ControlFlow::Break(c)) | Right(ControlFlow::Break(c)) => ControlFlow::Break(c)
+        }
+    }
+
+    /// Inverse of ```transpose```, converting ```Break(c)``` into ```Left(Break(c))```
+    #[inline(always)]
+    pub fn expand_control_flow (flow: ControlFlow<C,Either<A,B>>) -> Self {
+        match flow {
+            ControlFlow::Continue(Left(x)) => Left(ControlFlow::Continue(x)),
+            ControlFlow::Continue(Right(x)) => Right(ControlFlow::Continue(x)),
+            ControlFlow::Break(c) => Left(ControlFlow::Break(c))
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::prelude::*;
+
+#[cfg(feature = "serialize")]
+use ::serde::{Serialize, Deserialize};
+
+/// Result of a validation that, unlike ```Result```, accumulates every error found instead of stopping at the first one.
+/// Errors are gathered into ```E```, which is usually a collection like ```Vec```.
+///
+/// ```Valid``` values correspond to ```Left``` and ```Ok``` values, and ```Invalid``` values to ```Right``` and ```Err``` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum Validated<E, T> {
+    Invalid (E),
+    Valid (T)
+}
+
+impl<E, T> Validated<E, T> {
+    /// Creates an ```Invalid``` value with a single error
+    #[inline(always)]
+    pub fn invalid<X> (error: X) -> Self where E: FromIterator<X> {
+        Self::Invalid(core::iter::once(error).collect())
+    }
+
+    /// Converts a ```Result``` into a ```Validated```, wrapping it's error (if any) into a collection
+    #[inline(always)]
+    pub fn from_result<X> (result: Result<T, X>) -> Self where E: FromIterator<X> {
+        match result {
+            Ok(x) => Self::Valid(x),
+            Err(e) => Self::invalid(e)
+        }
+    }
+
+    /// Returns `true` if the value is `Valid`
+    #[inline(always)]
+    pub const fn is_valid (&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    /// Returns `true` if the value is `Invalid`
+    #[inline(always)]
+    pub const fn is_invalid (&self) -> bool {
+        matches!(self, Self::Invalid(_))
+    }
+
+    /// Returns the valid value, if any
+    #[inline(always)]
+    pub fn valid (self) -> Option<T> {
+        match self {
+            Self::Valid(x) => Some(x),
+            Self::Invalid(_) => None
+        }
+    }
+
+    /// Returns the errors, if any
+    #[inline(always)]
+    pub fn errors (self) -> Option<E> {
+        match self {
+            Self::Valid(_) => None,
+            Self::Invalid(e) => Some(e)
+        }
+    }
+
+    /// Returns a new `Validated` with references to the values inside
+    #[inline(always)]
+    pub const fn as_ref (&self) -> Validated<&E, &T> {
+        match self {
+            Self::Invalid(e) => Validated::Invalid(e),
+            Self::Valid(x) => Validated::Valid(x)
+        }
+    }
+
+    /// Returns a new `Validated` with mutable references to the values inside
+    #[inline(always)]
+    pub fn as_mut (&mut self) -> Validated<&mut E, &mut T> {
+        match self {
+            Self::Invalid(e) => Validated::Invalid(e),
+            Self::Valid(x) => Validated::Valid(x)
+        }
+    }
+
+    #[inline(always)]
+    pub fn map<U, F: FnOnce(T) -> U> (self, f: F) -> Validated<E, U> {
+        match self {
+            Self::Invalid(e) => Validated::Invalid(e),
+            Self::Valid(x) => Validated::Valid(f(x))
+        }
+    }
+
+    #[inline(always)]
+    pub fn map_errors<X, F: FnOnce(E) -> X> (self, f: F) -> Validated<X, T> {
+        match self {
+            Self::Invalid(e) => Validated::Invalid(f(e)),
+            Self::Valid(x) => Validated::Valid(x)
+        }
+    }
+
+    /// Combines the valid values of ```self``` and ```other``` with ```f```.
+    /// If any of them is invalid, the errors of both are returned, starting with the ones of ```self```.
+    #[inline]
+    pub fn combine<U, V, F: FnOnce(T, U) -> V> (self, other: Validated<E, U>, f: F) -> Validated<E, V> where E: Extend<E::Item> + IntoIterator {
+        match (self, other) {
+            (Self::Valid(x), Validated::Valid(y)) => Validated::Valid(f(x, y)),
+            (Self::Invalid(mut e), Validated::Invalid(other)) => {
+                e.extend(other);
+                Validated::Invalid(e)
+            },
+            (Self::Invalid(e), _) | (_, Validated::Invalid(e)) => Validated::Invalid(e)
+        }
+    }
+
+    /// Returns both valid values, or the errors of both if any of them is invalid
+    #[inline(always)]
+    pub fn zip<U> (self, other: Validated<E, U>) -> Validated<E, (T, U)> where E: Extend<E::Item> + IntoIterator {
+        self.combine(other, |x, y| (x, y))
+    }
+
+    /// Returns ```other``` if ```self``` is valid, or the errors of both if any of them is invalid
+    #[inline(always)]
+    pub fn and<U> (self, other: Validated<E, U>) -> Validated<E, U> where E: Extend<E::Item> + IntoIterator {
+        self.combine(other, |_, y| y)
+    }
+
+    /// Maps every value of ```iter``` with ```f```, collecting the valid values into ```C```, or every error if any of them is invalid
+    pub fn traverse<I: IntoIterator, C: FromIterator<T>, F: FnMut(I::Item) -> Self> (iter: I, f: F) -> Validated<E, C> where E: Extend<E::Item> + IntoIterator {
+        iter.into_iter().map(f).collect()
+    }
+
+    /// Collects the valid values of ```iter``` into ```C```, or every error if any of them is invalid
+    #[inline(always)]
+    pub fn sequence<I: IntoIterator<Item = Self>, C: FromIterator<T>> (iter: I) -> Validated<E, C> where E: Extend<E::Item> + IntoIterator {
+        iter.into_iter().collect()
+    }
+}
+
+/// Collects every valid value, or every error if any of the values is invalid
+impl<E: Extend<E::Item> + IntoIterator, T, C: FromIterator<T>> FromIterator<Validated<E, T>> for Validated<E, C> {
+    fn from_iter<I: IntoIterator<Item = Validated<E, T>>> (iter: I) -> Self {
+        let mut errors = None::<E>;
+        let valid = iter.into_iter().filter_map(|x| match x {
+            Validated::Valid(x) => Some(x),
+            Validated::Invalid(e) => {
+                match errors {
+                    Some(ref mut errors) => errors.extend(e),
+                    None => errors = Some(e)
+                }
+                None
+            }
+        }).collect::<C>();
+
+        match errors {
+            Some(e) => Self::Invalid(e),
+            None => Self::Valid(valid)
+        }
+    }
+}
+
+impl<E, T> From<Result<T, E>> for Validated<E, T> {
+    #[inline(always)]
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(x) => Self::Valid(x),
+            Err(e) => Self::Invalid(e)
+        }
+    }
+}
+
+impl<E, T> From<Validated<E, T>> for Result<T, E> {
+    #[inline(always)]
+    fn from(value: Validated<E, T>) -> Self {
+        match value {
+            Validated::Valid(x) => Ok(x),
+            Validated::Invalid(e) => Err(e)
+        }
+    }
+}
+
+impl<E, T> From<Either<T, E>> for Validated<E, T> {
+    #[inline(always)]
+    fn from(value: Either<T, E>) -> Self {
+        match value {
+            Left(x) => Self::Valid(x),
+            Right(e) => Self::Invalid(e)
+        }
+    }
+}
+
+impl<E, T> From<Validated<E, T>> for Either<T, E> {
+    #[inline(always)]
+    fn from(value: Validated<E, T>) -> Self {
+        match value {
+            Validated::Valid(x) => Left(x),
+            Validated::Invalid(e) => Right(e)
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use elor::prelude::*;
 
-#[cfg(any(feature = "std", feature = "alloc"))]
+#[cfg(feature = "alloc")]
 #[test]
 fn split () {
     use elor::iter::*;
@@ -42,6 +42,41 @@ fn split_arc () {
     }
 
     handle.join().unwrap();
+
+    for _ in 0..64 {
+        let alpha = (0..10_000u32).map(|x| if x % 3 == 0 { Right(x) } else { Left(x) });
+        let (left, right) = alpha.split_either_arc();
+
+        let handle = std::thread::spawn(move || left.collect::<Vec<_>>());
+        let right = right.collect::<Vec<_>>();
+        let left = handle.join().unwrap();
+
+        assert_eq!(left, (0..10_000).filter(|x| x % 3 != 0).collect::<Vec<_>>());
+        assert_eq!(right, (0..10_000).filter(|x| x % 3 == 0).collect::<Vec<_>>());
+    }
+
+    let alpha = (0..100_000u32).map(|x| if x % 2 == 0 { Left(x) } else { Right(x) });
+    let (left, right) = alpha.split_either_arc();
+
+    let handles = (0..8).map(|i| {
+        let left = left.clone();
+        let right = right.clone();
+        std::thread::spawn(move || match i % 2 {
+            0 => left.collect::<Vec<_>>(),
+            _ => right.collect::<Vec<_>>()
+        })
+    }).collect::<Vec<_>>();
+    drop((left, right));
+
+    let mut all = Vec::new();
+    for handle in handles {
+        let items = handle.join().unwrap();
+        assert!(items.windows(2).all(|x| x[0] < x[1]));
+        all.extend(items);
+    }
+
+    all.sort_unstable();
+    assert_eq!(all, (0..100_000).collect::<Vec<_>>());
 }
 
 #[test]
@@ -51,4 +86,779 @@ fn iter () {
     for i in iter.into_same_iter() {
         println!("{i}")
     }
-}
\ No newline at end of file
+}
+#[test]
+fn nary () {
+    use elor::Either4;
+
+    let alpha : Either4<u8, &str, f32, Vec<u8>> = Either4::Third(2.5);
+    assert!(alpha.is_third());
+    assert_eq!(alpha.clone().third(), Some(2.5));
+    assert_eq!(alpha.clone().map_third(|x| x as u32), Either4::Third(2));
+    assert_eq!(alpha.clone().fold(|x| x as f32, |x| x.len() as f32, |x| x * 2., |x| x.len() as f32), 5.);
+
+    let nested : Either<u8, Either<&str, Either<f32, Vec<u8>>>> = alpha.clone().into();
+    assert_eq!(nested, Right(Right(Left(2.5))));
+    assert_eq!(Either4::from(nested), alpha);
+
+    let iter : Either4<std::ops::Range<u8>, std::iter::Empty<u8>, std::option::IntoIter<u8>, std::vec::IntoIter<u8>> = Either4::First(0..3);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Either4::First(0), Either4::First(1), Either4::First(2)]);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn delegate () {
+    #[elor::delegate]
+    trait Storage<K> {
+        type Error;
+
+        fn get (&self, key: &K) -> Option<u32>;
+        fn insert (&mut self, key: K, value: u32) -> Result<(), Self::Error>;
+        fn fork (&self) -> Self;
+        fn into_len (self) -> usize;
+    }
+
+    #[derive(Clone, Default)]
+    struct Memory (std::collections::HashMap<u8, u32>);
+
+    struct ReadOnly;
+
+    impl Storage<u8> for Memory {
+        type Error = std::convert::Infallible;
+
+        fn get (&self, key: &u8) -> Option<u32> { self.0.get(key).copied() }
+        fn insert (&mut self, key: u8, value: u32) -> Result<(), Self::Error> { self.0.insert(key, value); Ok(()) }
+        fn fork (&self) -> Self { self.clone() }
+        fn into_len (self) -> usize { self.0.len() }
+    }
+
+    impl Storage<u8> for ReadOnly {
+        type Error = &'static str;
+
+        fn get (&self, _: &u8) -> Option<u32> { None }
+        fn insert (&mut self, _: u8, _: u32) -> Result<(), Self::Error> { Err("read only") }
+        fn fork (&self) -> Self { ReadOnly }
+        fn into_len (self) -> usize { 0 }
+    }
+
+    let mut alpha : Either<Memory, ReadOnly> = Left(Memory::default());
+    assert_eq!(alpha.insert(1, 2).map_err(|e| e.is_left()), Ok(()));
+    assert_eq!(alpha.get(&1), Some(2));
+    assert!(alpha.fork().is_left());
+    assert_eq!(alpha.into_len(), 1);
+
+    let mut beta : Either<Memory, ReadOnly> = Right(ReadOnly);
+    assert_eq!(beta.insert(1, 2), Err(Right("read only")));
+    assert_eq!(beta.get(&1), None);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn auto_either () {
+    #[elor::auto_either]
+    fn describe (x: i32) -> impl std::fmt::Display {
+        if x < 0 {
+            return "negative";
+        }
+
+        match x {
+            0 => '0',
+            1 | 2 => {
+                let y = x as f32 / 2.;
+                y
+            },
+            _ if x > 100 => unreachable!(),
+            _ => format!("{x}!")
+        }
+    }
+
+    #[elor::auto_either(Iterator)]
+    fn evens (limit: u32, boxed: bool) -> impl Iterator<Item = u32> {
+        if limit == 0 {
+            return std::iter::empty();
+        }
+
+        match boxed {
+            true => Box::new((0..limit).filter(|x| x % 2 == 0)) as Box<dyn Iterator<Item = u32>>,
+            false => (0..limit).step_by(2)
+        }
+    }
+
+    assert_eq!(describe(-1).to_string(), "negative");
+    assert_eq!(describe(0).to_string(), "0");
+    assert_eq!(describe(1).to_string(), "0.5");
+    assert_eq!(describe(7).to_string(), "7!");
+
+    assert_eq!(evens(0, true).count(), 0);
+    assert_eq!(evens(7, true).collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+    assert_eq!(evens(7, false).collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+}
+
+#[cfg(all(feature = "derive", feature = "async"))]
+#[tokio::test]
+async fn auto_either_future () {
+    #[elor::auto_either(Future)]
+    fn answer (fast: bool) -> impl std::future::Future<Output = u32> {
+        match fast {
+            true => std::future::ready(42),
+            false => async { tokio::task::yield_now().await; 42 }
+        }
+    }
+
+    assert_eq!(answer(true).await, 42);
+    assert_eq!(answer(false).await, 42);
+}
+
+#[cfg(all(feature = "async", feature = "std"))]
+#[tokio::test]
+async fn split_stream () {
+    use elor::EitherStream;
+    use futures::StreamExt;
+
+    let alpha = futures::stream::iter(vec![Left(1), Right(2.0), Left(3), Left(5), Right(4.0), Right(6.0)]);
+    let (left, right) = alpha.then(|x| async move { tokio::task::yield_now().await; x }).split_either();
+
+    let left = tokio::spawn(left.collect::<Vec<_>>());
+    let right = tokio::spawn(right.collect::<Vec<_>>());
+    assert_eq!(left.await.unwrap(), vec![1, 3, 5]);
+    assert_eq!(right.await.unwrap(), vec![2.0, 4.0, 6.0]);
+
+    let beta = || futures::stream::iter(vec![Left(1), Right('a'), Left(2)]);
+    assert_eq!(beta().lefts().collect::<Vec<_>>().await, vec![1, 2]);
+    assert_eq!(beta().rights().collect::<Vec<_>>().await, vec!['a']);
+    assert_eq!(beta().map_left(|x| x * 10).collect::<Vec<_>>().await, vec![Left(10), Right('a'), Left(20)]);
+    assert_eq!(beta().partition::<Vec<_>, String>().await, (vec![1, 2], String::from("a")));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn serde_tagged () {
+    use elor::{LeftRight, iter::LRIter};
+    use serde::{Serialize, Deserialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point { x: u32 }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        #[serde(with = "elor::serde::externally_tagged")]
+        external: Either<u32, u64>,
+        #[serde(with = "elor::serde::adjacently_tagged")]
+        adjacent: LeftRight<String>,
+        #[serde(with = "elor::serde::internally_tagged")]
+        internal: Either<Point, Point>
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "elor::serde::externally_tagged::lr_iter")]
+        ports: LRIter<std::ops::Range<u16>, std::ops::RangeInclusive<u16>>
+    }
+
+    let alpha = Payload {
+        external: Right(1),
+        adjacent: Right("one".to_string()),
+        internal: Right(Point { x: 1 })
+    };
+
+    let json = serde_json::to_string(&alpha).unwrap();
+    assert_eq!(json, r#"{"external":{"Right":1},"adjacent":{"tag":"Right","content":"one"},"internal":{"tag":"Right","x":1}}"#);
+    assert_eq!(serde_json::from_str::<Payload>(&json).unwrap(), alpha);
+
+    let json = r#"{"ports":{"Right":{"start":80,"end":83}}}"#;
+    let config = serde_json::from_str::<Config>(json).unwrap();
+    assert_eq!(serde_json::to_string(&config).unwrap(), json);
+    assert_eq!(config.ports.collect::<Vec<_>>(), vec![80, 81, 82, 83]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io () {
+    use std::io::{Read, Write, BufRead, Seek, SeekFrom, Cursor};
+
+    let mut alpha : Either<Cursor<Vec<u8>>, std::io::Empty> = Left(Cursor::new(b"hello\nworld".to_vec()));
+    let mut line = String::new();
+    alpha.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+
+    let mut rest = String::new();
+    alpha.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "world");
+
+    alpha.seek(SeekFrom::Start(0)).unwrap();
+    let mut buf = [0; 5];
+    alpha.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+
+    let mut beta : Either<Vec<u8>, std::io::Sink> = Left(Vec::new());
+    write!(beta, "{} {}", 1, 2).unwrap();
+    beta.flush().unwrap();
+    assert_eq!(beta.unwrap_left(), b"1 2");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_io () {
+    use futures::io::{AsyncWriteExt, AsyncBufReadExt, Cursor};
+
+    let mut alpha : Either<Cursor<Vec<u8>>, futures::io::Empty> = Left(Cursor::new(b"hello\nworld".to_vec()));
+    let mut line = String::new();
+    alpha.read_line(&mut line).await.unwrap();
+    assert_eq!(line, "hello\n");
+
+    let mut beta : Either<futures::io::Sink, Cursor<Vec<u8>>> = Right(Cursor::new(Vec::new()));
+    beta.write_all(b"hello").await.unwrap();
+    beta.close().await.unwrap();
+    assert_eq!(beta.unwrap_right().into_inner(), b"hello");
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn tokio_io () {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, AsyncSeekExt};
+
+    let (client, mut server) = tokio::io::duplex(64);
+    let mut alpha : Either<tokio::io::DuplexStream, tokio::io::Empty> = Left(client);
+    alpha.write_all(b"ping").await.unwrap();
+
+    let mut buf = [0; 4];
+    server.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+
+    let mut beta : Either<tokio::io::Empty, std::io::Cursor<Vec<u8>>> = Right(std::io::Cursor::new(b"hello".to_vec()));
+    beta.seek(std::io::SeekFrom::Start(1)).await.unwrap();
+    let mut rest = String::new();
+    beta.read_to_string(&mut rest).await.unwrap();
+    assert_eq!(rest, "ello");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn sink () {
+    use futures::{SinkExt, StreamExt};
+
+    let (tx, rx) = futures::channel::mpsc::unbounded::<u32>();
+    let mut alpha : Either<futures::channel::mpsc::UnboundedSender<u32>, futures::sink::Drain<u32>> = Left(tx);
+    alpha.send(1).await.unwrap();
+    alpha.send(2).await.unwrap();
+    alpha.close().await.unwrap();
+    assert_eq!(rx.collect::<Vec<_>>().await, vec![1, 2]);
+
+    let (tx, rx) = futures::channel::mpsc::unbounded::<u32>();
+    let beta : Either<futures::channel::mpsc::UnboundedSender<u32>, futures::channel::mpsc::UnboundedSender<u32>> = Right(tx);
+    let mut beta = beta.into_same_sink();
+    let result : Result<(), futures::channel::mpsc::SendError> = beta.send(3).await;
+    result.unwrap();
+    drop(beta);
+    assert_eq!(rx.collect::<Vec<_>>().await, vec![3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn split_sync () {
+    use elor::iter::*;
+
+    for _ in 0..64 {
+        let alpha = (0..10_000u32).map(|x| if x % 3 == 0 { Right(x) } else { Left(x) });
+        let (left, right) = alpha.split_either_sync();
+
+        let handle = std::thread::spawn(move || left.collect::<Vec<_>>());
+        let right = right.collect::<Vec<_>>();
+        let left = handle.join().unwrap();
+
+        assert_eq!(left, (0..10_000).filter(|x| x % 3 != 0).collect::<Vec<_>>());
+        assert_eq!(right, (0..10_000).filter(|x| x % 3 == 0).collect::<Vec<_>>());
+    }
+
+    let alpha = (0..100_000u32).map(|x| if x % 2 == 0 { Left(x) } else { Right(x) });
+    let (left, right) = alpha.split_either_sync();
+
+    let handles = (0..8).map(|i| {
+        let left = left.clone();
+        let right = right.clone();
+        std::thread::spawn(move || match i % 2 {
+            0 => left.collect::<Vec<_>>(),
+            _ => right.collect::<Vec<_>>()
+        })
+    }).collect::<Vec<_>>();
+
+    let mut all = Vec::new();
+    for handle in handles {
+        let items = handle.join().unwrap();
+        assert!(items.windows(2).all(|x| x[0] < x[1]));
+        all.extend(items);
+    }
+
+    all.sort_unstable();
+    assert_eq!(all, (0..100_000).collect::<Vec<_>>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn split_bounded () {
+    use elor::iter::*;
+    use std::sync::{Arc, Mutex};
+
+    let alpha = || (0..10u32).map(|x| if x < 8 { Right(x) } else { Left(x) });
+
+    let (mut left, mut right) = alpha().split_either_bounded(4, Overflow::Error);
+    assert_eq!(left.size_hint(), (0, Some(10)));
+    assert_eq!(left.try_next(), Err(BufferFull));
+    assert_eq!(left.next(), None);
+    assert!(left.overflowed() && !right.overflowed());
+    assert_eq!(right.buffered(), 4);
+    assert_eq!(right.size_hint(), (5, Some(10)));
+    assert_eq!(right.by_ref().take(4).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert!(!left.overflowed());
+    assert_eq!(left.collect::<Vec<_>>(), vec![8, 9]);
+    assert_eq!(right.collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+
+    // plain iteration stops at the overflow, and resumes from the same value once there's room
+    let (mut left, mut right) = alpha().split_either_arc_bounded(4, Overflow::Error);
+    assert_eq!(left.by_ref().count(), 0);
+    assert!(left.overflowed());
+    assert_eq!(right.next(), Some(0));
+    assert_eq!(left.next(), None);
+    assert_eq!(right.by_ref().take(4).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(left.collect::<Vec<_>>(), vec![8, 9]);
+    assert_eq!(right.collect::<Vec<_>>(), vec![5, 6, 7]);
+
+    let (left, right) = alpha().split_either_bounded(4, Overflow::DropOldest);
+    assert_eq!(left.collect::<Vec<_>>(), vec![8, 9]);
+    assert_eq!(right.collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+
+    let spilled = Arc::new(Mutex::new(Vec::new()));
+    let spill = spilled.clone();
+    let (left, right) = alpha().split_either_bounded(4, Overflow::Spill(Box::new(move |x| spill.lock().unwrap().push(x))));
+    assert_eq!(left.collect::<Vec<_>>(), vec![8, 9]);
+    assert_eq!(right.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(*spilled.lock().unwrap(), vec![Right(4), Right(5), Right(6), Right(7)]);
+
+    let alpha = || (0..10_000u32).map(|x| if x % 5 == 0 { Left(x) } else { Right(x) });
+    let (mut left, right) = alpha().split_either_arc_bounded(16, Overflow::Block);
+    let handle = std::thread::spawn(move || right.collect::<Vec<_>>());
+
+    let mut lefts = Vec::new();
+    while let Some(x) = left.next() {
+        assert!(left.buffered() <= 16);
+        lefts.push(x);
+    }
+
+    let mut rights = handle.join().unwrap();
+    lefts.sort_unstable();
+    rights.sort_unstable();
+    assert_eq!(lefts, (0..10_000).filter(|x| x % 5 == 0).collect::<Vec<_>>());
+    assert_eq!(rights, (0..10_000).filter(|x| x % 5 != 0).collect::<Vec<_>>());
+
+    // blocked sides give up once the other side is dropped
+    let (left, right) = alpha().split_either_arc_bounded(16, Overflow::Block);
+    drop(right);
+    assert_eq!(left.count(), 2_000);
+}
+
+#[test]
+fn split_in () {
+    use elor::iter::*;
+
+    let alpha = (0..10u32).map(|x| if x % 4 == 0 { Left(x) } else { Right(x.to_string()) });
+    let mut storage = SplitStorage::<_, _, _, 4>::new();
+    let (mut left, mut right) = alpha.split_either_in(&mut storage);
+
+    assert_eq!(left.next(), Some(0));
+    assert_eq!(left.next(), Some(4));
+    assert_eq!(right.buffered(), 3);
+    assert_eq!(left.try_next(), Err(BufferFull));
+    assert_eq!(left.next(), None);
+    assert!(left.overflowed());
+    assert_eq!(left.size_hint(), (0, Some(3)));
+    assert_eq!(right.buffered(), 4);
+
+    assert_eq!(right.by_ref().take(5).collect::<Vec<_>>(), ["1", "2", "3", "5", "6"]);
+    assert!(!left.overflowed());
+    assert_eq!(left.collect::<Vec<_>>(), vec![8]);
+    assert_eq!(right.size_hint(), (2, Some(2)));
+
+    // buffered values are dropped along with the storage
+    let mut storage = SplitStorage::<_, _, _, 16>::new();
+    let (left, _) = (0..16).map(|x| if x == 15 { Left(x) } else { Right(vec![x]) }).split_either_in(&mut storage);
+    assert_eq!(left.collect::<Vec<_>>(), vec![15]);
+}
+
+#[test]
+fn iter_adapters () {
+    use elor::iter::*;
+
+    let alpha = [Left(1), Right("a"), Left(2), Left(3), Right("bc")];
+    let iter = || alpha.iter().copied();
+
+    assert_eq!(iter().lefts().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(iter().rights().rev().collect::<Vec<_>>(), vec!["bc", "a"]);
+    assert_eq!(iter().map_left(|x| x * 2).lefts().sum::<i32>(), 12);
+    assert_eq!(iter().map_right(str::len).len(), 5);
+    assert_eq!(iter().map_either(|x| x as usize, str::len).into_same().collect::<Vec<_>>(), vec![1, 1, 2, 3, 2]);
+    assert_eq!(iter().filter_left(|x| x % 2 == 1).collect::<Vec<_>>(), vec![Left(1), Right("a"), Left(3), Right("bc")]);
+    assert_eq!(iter().left_or_else(|x| x.len() as i32 * 10).collect::<Vec<_>>(), vec![1, 10, 2, 3, 20]);
+
+    let flat = iter().flat_map_left(|x| 0..x);
+    assert_eq!(flat.clone().collect::<Vec<_>>(), vec![Left(0), Right("a"), Left(0), Left(1), Left(0), Left(1), Left(2), Right("bc")]);
+    assert_eq!(flat.rev().collect::<Vec<_>>(), vec![Right("bc"), Left(2), Left(1), Left(0), Left(1), Left(0), Right("a"), Left(0)]);
+
+    // yields None once, and then keeps going
+    #[derive(Clone)]
+    struct Unfused (u8);
+    impl Iterator for Unfused {
+        type Item = u8;
+        fn next (&mut self) -> Option<u8> {
+            self.0 += 1;
+            (self.0 != 2).then_some(self.0)
+        }
+    }
+    impl DoubleEndedIterator for Unfused {
+        fn next_back (&mut self) -> Option<u8> {
+            self.next()
+        }
+    }
+
+    let mut flat = [Left::<u8, ()>(0)].into_iter().flat_map_left(Unfused);
+    assert_eq!(flat.next_back(), Some(Left(1)));
+    assert_eq!(flat.next(), None);
+    assert_eq!(flat.next(), None);
+
+    let mut seen = Vec::new();
+    assert_eq!(iter().inspect_left(|x| seen.push(*x)).count_sides(), (3, 2));
+    assert_eq!(seen, vec![1, 2, 3]);
+
+    assert!(!iter().all_left());
+    assert!(iter().any_right());
+    assert!(iter().take(1).all_left());
+}
+
+#[test]
+fn partition () {
+    use elor::iter::*;
+    use std::collections::{BTreeMap, HashSet};
+
+    let alpha = vec![Left(1), Right('a'), Left(2), Left(1), Right('b')];
+    let (left, right): (Vec<_>, String) = alpha.iter().copied().partition_either();
+    assert_eq!(left, vec![1, 2, 1]);
+    assert_eq!(right, "ab");
+
+    let (left, right): (HashSet<_>, Vec<_>) = alpha.into_iter().partition_either();
+    assert_eq!(left, HashSet::from([1, 2]));
+    assert_eq!(right, vec!['a', 'b']);
+
+    let (even, odd): (BTreeMap<_, _>, Vec<_>) = (0..6).partition_map(|x| match x % 2 {
+        0 => Left((x, x * x)),
+        _ => Right(x)
+    });
+
+    assert_eq!(even, BTreeMap::from([(0, 0), (2, 4), (4, 16)]));
+    assert_eq!(odd, vec![1, 3, 5]);
+}
+
+#[test]
+fn from_iter () {
+    use elor::iter::*;
+
+    let alpha = [Left(1), Left(2), Right("stop"), Left(3), Right("late")];
+    let mut consumed = 0;
+    let collected: Either<Vec<i32>, &str> = alpha.iter().copied().inspect(|_| consumed += 1).collect();
+    assert_eq!(collected, Right("stop"));
+    assert_eq!(consumed, 3);
+
+    assert_eq!((1..=4).map(Left::<i32, ()>).collect::<Either<Vec<_>, _>>(), Left(vec![1, 2, 3, 4]));
+    assert_eq!((1..=4).map(Left::<i32, ()>).sum::<Either<i32, _>>(), Left(10));
+    assert_eq!((1..=4).map(Left::<i32, ()>).product::<Either<i32, _>>(), Left(24));
+    assert_eq!(alpha.iter().copied().sum::<Either<i32, _>>(), Right("stop"));
+
+    let beta = [Right(2), Right(3), Left("stop"), Right(4)];
+    assert_eq!(beta.iter().copied().collect_right::<Vec<i32>>(), Left("stop"));
+    assert_eq!(beta[..2].iter().copied().collect_right::<Vec<i32>>(), Right(vec![2, 3]));
+    assert_eq!(beta[..2].iter().copied().sum_right::<i32>(), Right(5));
+    assert_eq!(beta.iter().copied().product_right::<i32>(), Left("stop"));
+}
+
+#[test]
+fn into_iter () {
+    let mut alpha: Either<Vec<u32>, [u32; 4]> = Right([1, 2, 3, 4]);
+    for x in alpha.iter_mut() {
+        *x *= 2;
+    }
+
+    let mut sum = 0;
+    for x in &alpha {
+        sum += x;
+    }
+
+    assert_eq!(sum, 20);
+    assert_eq!(alpha.iter().rev().copied().collect::<Vec<_>>(), vec![8, 6, 4, 2]);
+    assert_eq!(alpha.into_same_iter().len(), 4);
+
+    let beta: Either<Vec<u32>, &str> = Right("ab");
+    assert_eq!(beta.map_right(str::chars).into_iter_either().collect::<Vec<_>>(), vec![Right('a'), Right('b')]);
+    assert_eq!(Left::<_, [u32; 0]>(vec![1]).into_iter_either().next(), Some(Left(1)));
+}
+
+#[test]
+fn iter_forwarding () {
+    let alpha: Either<_, std::vec::IntoIter<u32>> = Left(1..=10u32);
+    let mut same = alpha.clone().into_same_iter();
+
+    assert_eq!(same.nth(2), Some(3));
+    assert_eq!(same.nth_back(1), Some(9));
+    assert_eq!(same.clone().count(), 5);
+    assert_eq!(same.clone().last(), Some(8));
+    assert_eq!(same.clone().fold(0, |acc, x| acc * 10 + x), 45678);
+    assert_eq!(same.rfold(Vec::new(), |mut acc, x| { acc.push(x); acc }), vec![8, 7, 6, 5, 4]);
+
+    let mut beta = alpha;
+    assert_eq!(beta.nth(8), Some(Left(9)));
+    assert_eq!(beta.clone().last(), Some(Left(10)));
+    assert_eq!(Iterator::fold(beta, 0, |acc, x| acc + x.fold(|x| x, |x| x)), 10);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn rayon () {
+    use elor::iter::*;
+    use rayon::prelude::*;
+
+    let data = (0..1_000u32).collect::<Vec<_>>();
+    let pick = |indexed: bool| LRIter::new(match indexed {
+        true => Left(data.par_iter().copied()),
+        false => Right(data.iter().copied().par_bridge())
+    });
+
+    assert_eq!(ParallelIterator::sum::<u32>(pick(true)), 499_500);
+    assert_eq!(ParallelIterator::sum::<u32>(pick(false)), 499_500);
+
+    let indexed: Either<_, rayon::vec::IntoIter<u32>> = Left(data.par_iter().map(|x| x * 2));
+    assert_eq!(indexed.len(), 1_000);
+    assert_eq!(indexed.rev().take(2).collect::<Vec<_>>(), vec![Left(1_998), Left(1_996)]);
+
+    let (even, odd): (Vec<_>, Vec<_>) = data.par_iter()
+        .map(|&x| if x % 2 == 0 { Left(x) } else { Right(x as u64) })
+        .par_partition_either();
+
+    assert_eq!(even, (0..1_000).step_by(2).collect::<Vec<_>>());
+    assert_eq!(odd, (1..1_000).step_by(2).collect::<Vec<_>>());
+}
+
+#[test]
+fn these () {
+    use elor::{iter::*, These};
+
+    assert_eq!(These::<u8, u8>::from_options(None, None), None);
+    assert_eq!(These::from(Left::<_, ()>(1)), These::Left(1));
+    assert_eq!(Either::try_from(These::<_, ()>::Left(1)), Ok(Left(1)));
+    assert_eq!(Either::try_from(These::Both(1, 'a')), Err((1, 'a')));
+    assert_eq!(<(Option<_>, Option<_>)>::from(These::Both(1, 'a')), (Some(1), Some('a')));
+    assert_eq!(These::try_from((None, Some('a'))), Ok(These::<u8, _>::Right('a')));
+    assert_eq!(These::<u8, _>::Right('a').or_default(), (0, 'a'));
+
+    let zipped = (1..4).zip_longest(['a']);
+    assert_eq!(zipped.len(), 3);
+    assert_eq!(zipped.clone().rev().collect::<Vec<_>>(), vec![These::Left(3), These::Left(2), These::Both(1, 'a')]);
+    assert_eq!(zipped.collect::<Vec<_>>(), vec![These::Both(1, 'a'), These::Left(2), These::Left(3)]);
+
+    let merged = [1, 3, 4, 7].into_iter().merge_join_by([2, 3, 7, 9], |x, y| x.cmp(y)).collect::<Vec<_>>();
+    assert_eq!(merged, vec![
+        These::Left(1), These::Right(2), These::Both(3, 3), These::Left(4),
+        These::Both(7, 7), These::Right(9)
+    ]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn join_either () {
+    use elor::iter::*;
+
+    fn split<I: Iterator<Item = Either<u32, char>>> (iter: I) -> (Vec<u32>, Vec<char>) {
+        let (left, right) = iter.split_either();
+        (left.collect(), right.collect())
+    }
+
+    let x = vec![1, 3, 4, 8, 9];
+    let y = vec!['a', 'e', 'h'];
+
+    let interleaved = interleave_either(x.clone(), y.clone()).collect::<Vec<_>>();
+    assert_eq!(&interleaved[..4], &[Left(1), Right('a'), Left(3), Right('e')]);
+    assert_eq!(split(interleaved.into_iter()), (x.clone(), y.clone()));
+
+    let mut interleaved = interleave_either(x.clone(), ['z']);
+    assert_eq!(interleaved.next(), Some(Left(1)));
+    assert_eq!(interleaved.clone().next(), Some(Right('z')));
+    assert_eq!(interleaved.nth(1), Some(Left(3)));
+    assert_eq!(interleaved.collect::<Vec<_>>(), vec![Left(4), Left(8), Left(9)]);
+
+    let merged = merge_either_by(x.clone(), y.clone(), |x, y| x.cmp(&(*y as u32 - 'a' as u32))).collect::<Vec<_>>();
+    assert_eq!(merged, vec![Right('a'), Left(1), Left(3), Left(4), Right('e'), Right('h'), Left(8), Left(9)]);
+    assert_eq!(split(merged.into_iter()), (x.clone(), y.clone()));
+
+    let chained = chain_either(x.clone(), y.clone());
+    assert_eq!(chained.size_hint(), (8, Some(8)));
+    assert_eq!(chained.clone().next_back(), Some(Right('h')));
+    assert_eq!(split(chained), (x, y));
+}
+
+#[test]
+fn bifunctor () {
+    use elor::{LeftRight, These, Validated, bifunctor::*};
+
+    let (f1, g1) = (|a: i32| a as i64 * 2, |b: u8| b as u32 + 1);
+    let (f2, g2) = (|a: i64| a - 3, |b: u32| b.to_string());
+    let push = |mut acc: Vec<String>, s: String| { acc.push(s); acc };
+
+    macro_rules! laws {
+        ($($x:expr),+) => {$(
+            let x = $x;
+            assert_eq!(x.bimap(|a| a, |b| b), x);
+            assert_eq!(x.bimap(|a| f2(f1(a)), |b| g2(g1(b))), x.bimap(f1, g1).bimap(f2, g2));
+            assert_eq!(x.first(f1).second(g1), x.bimap(f1, g1));
+            assert_eq!(
+                x.bimap(f1, g1).bifold(Vec::new(), |acc, a| push(acc, a.to_string()), |acc, b| push(acc, b.to_string())),
+                x.bifold(Vec::new(), |acc, a| push(acc, f1(a).to_string()), |acc, b| push(acc, g1(b).to_string()))
+            );
+            assert_eq!(x.bitraverse(Ok::<_, ()>, Ok::<_, ()>), Ok(x));
+            assert_eq!(x.bitraverse(|a| Ok::<_, ()>(f1(a)), |b| Ok::<_, ()>(g1(b))), Ok(x.bimap(f1, g1)));
+        )+};
+    }
+
+    laws!(Left::<i32, u8>(1), Right::<i32, u8>(2), Ok::<i32, u8>(3), Err::<i32, u8>(4), (5, 6u8), These::<i32, u8>::Both(7, 8),
+        Validated::<u8, i32>::Valid(9), Validated::<u8, i32>::Invalid(10));
+
+    let lr : LeftRight<i32> = Right(3);
+    assert_eq!(lr.bimap(|x| x + 1, |x| x * 2), Right(6));
+    assert!((1, 2).biall(|x| x > 0, |y| y > 1));
+    assert!(!Left::<i32, u8>(1).biany(|x| x > 1, |_| true));
+    assert_eq!((1, -2).bitraverse_option(|x: i32| x.checked_sub(1), |y: i32| u8::try_from(y).ok()), None);
+    assert_eq!((1, 2).bifold(0, |acc, x| acc * 10 + x, |acc, y| acc * 10 + y), 12);
+
+    let x = Validated::<u8, i32>::Valid(1);
+    assert_eq!(Either::from(x.bimap(f1, g1)), Either::from(x).bimap(f1, g1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn validated () {
+    use elor::Validated;
+
+    fn parse (x: &str) -> Validated<Vec<String>, u32> {
+        Validated::from_result(x.parse::<u32>().map_err(|_| format!("invalid number: {x}")))
+    }
+
+    let age = parse("30");
+    let height = parse("tall");
+    let weight = parse("heavy");
+
+    assert_eq!(age.clone().zip(parse("180")), Validated::Valid((30, 180)));
+    assert_eq!(age.clone().and(height.clone()), height);
+    assert_eq!(
+        height.clone().combine(weight.clone(), |x, y| x + y),
+        Validated::Invalid(vec!["invalid number: tall".to_string(), "invalid number: heavy".to_string()])
+    );
+
+    let all : Validated<Vec<String>, Vec<u32>> = Validated::traverse(["1", "2", "3"], parse);
+    assert_eq!(all, Validated::Valid(vec![1, 2, 3]));
+
+    let all : Validated<Vec<String>, Vec<u32>> = Validated::sequence([age, height, weight]);
+    assert_eq!(all.clone().errors().map(|x| x.len()), Some(2));
+    assert!(Either::from(all.clone()).is_right());
+    assert_eq!(Validated::from(Left::<u8, Vec<()>>(1)), Validated::Valid(1));
+    assert!(Result::from(all).is_err());
+    assert_eq!(Validated::from(Ok::<u8, Vec<()>>(1)).valid(), Some(1));
+}
+
+#[test]
+fn transpose () {
+    use core::{task::Poll, ops::ControlFlow};
+
+    let x : Either<Option<u8>, char> = Left(None);
+    assert_eq!(x.transpose_left(), None);
+    assert_eq!(Either::expand_left_option(x.transpose_left()), x);
+    assert_eq!(Right::<u8, Option<char>>(None).transpose_right(), None);
+    assert_eq!(Right::<u8, Option<char>>(Some('a')).transpose_right(), Some(Right('a')));
+    let x : Either<Option<u8>, Option<char>> = Right(Some('a'));
+    assert_eq!(x.transpose(), Some(Right('a')));
+    assert_eq!(Either::expand_option(x.transpose()), x);
+    assert_eq!(Either::<Option<u8>, Option<char>>::expand_option(Right(None).transpose()), Left(None));
+
+    let x : Either<u8, Result<char, ()>> = Right(Err(()));
+    assert_eq!(x.transpose_right(), Err(()));
+    assert_eq!(Either::expand_right_result(x.transpose_right()), x);
+    assert_eq!(Left::<Result<u8, ()>, char>(Ok(1)).transpose_left(), Ok(Left(1)));
+    let x : Either<Result<u8, &str>, Result<char, &str>> = Right(Err("eof"));
+    assert_eq!(x.transpose(), Err("eof"));
+    assert_eq!(Either::expand_shared_result(x.transpose()), Left(Err("eof")));
+    assert_eq!(Either::expand_shared_result(Left::<Result<u8, ()>, Result<char, ()>>(Ok(1)).transpose()), Left(Ok(1)));
+
+    let x : Either<Poll<u8>, char> = Right('a');
+    assert_eq!(x.transpose_left(), Poll::Ready(Right('a')));
+    assert_eq!(Either::expand_left_poll(x.transpose_left()), x);
+    assert_eq!(Left::<Poll<u8>, Poll<char>>(Poll::Pending).transpose(), Poll::Pending);
+    let x : Either<Poll<u8>, Poll<char>> = Right(Poll::Ready('a'));
+    assert_eq!(Either::expand_poll(x.transpose()), x);
+
+    let x : Either<u8, ControlFlow<&str, char>> = Right(ControlFlow::Break("stop"));
+    assert_eq!(x.transpose_right(), ControlFlow::Break("stop"));
+    assert_eq!(Either::expand_right_control_flow(x.transpose_right()), x);
+    let x : Either<ControlFlow<(), u8>, ControlFlow<(), char>> = Left(ControlFlow::Continue(1));
+    assert_eq!(x.transpose(), ControlFlow::Continue(Left(1)));
+    assert_eq!(Either::expand_control_flow(x.transpose()), x);
+}
+
+#[test]
+fn nest () {
+    use core::num::Wrapping;
+    use elor::{Flatten, Leaf, AsLeaf};
+
+    let x : Either<u8, Either<char, bool>> = Right(Left('a'));
+    assert_eq!(x.assoc_left(), Left(Right('a')));
+    assert_eq!(x.assoc_left().assoc_right(), x);
+
+    let x : Either<u8, bool> = Right(true);
+    assert_eq!(x.inject_left::<char>(), Left(Right(true)));
+    assert_eq!(x.inject_right::<char>(), Right(Right(true)));
+    assert_eq!(x.inject_middle::<char>(), Right(Right(true)));
+
+    #[derive(Debug, PartialEq)]
+    struct Name (&'static str);
+    impl Leaf for Name {}
+
+    type Nested = Either<Either<u8, Name>, Either<Either<char, bool>, f32>>;
+    type Flat = Either<u8, Either<Name, Either<char, Either<bool, f32>>>>;
+
+    let x : Nested = Right(Left(Right(true)));
+    let flat : Flat = x.flatten_nested();
+    assert_eq!(flat, Right(Right(Right(Left(true)))));
+
+    let x : Either<Either<u8, Name>, u8> = Left(Right(Name("elor")));
+    assert_eq!(x.flatten_nested(), Right(Left(Name("elor"))));
+    assert_eq!(Left::<u8, char>(1).flatten_nested(), Left(1));
+
+    let x : Either<Either<AsLeaf<Wrapping<u8>>, char>, u8> = Left(Left(AsLeaf(Wrapping(1))));
+    assert_eq!(x.flatten_nested().map_left(AsLeaf::into_inner), Left(Wrapping(1)));
+}
+
+#[test]
+fn factor () {
+    let x : Either<(usize, u8), (usize, char)> = Right((4, 'a'));
+    assert_eq!(x.factor_first(), (4, Right('a')));
+    assert_eq!(Either::distribute(x.factor_first()), x);
+
+    let x : Either<(u8, usize), (char, usize)> = Left((1, 2));
+    assert_eq!(x.factor_second(), (Left(1), 2));
+    assert_eq!(Either::distribute_second(x.factor_second()), x);
+
+    assert_eq!(Left::<Option<u8>, Option<char>>(None).factor_none(), None);
+    assert_eq!(Right::<Option<u8>, Option<char>>(Some('a')).factor_none(), Some(Right('a')));
+    assert_eq!(Left::<Result<u8, &str>, Result<char, &str>>(Err("eof")).factor_err(), Err("eof"));
+
+    let x : Either<Option<u8>, Option<char>> = Right(Some('a'));
+    assert_eq!(Either::distribute_none(x.factor_none()), x);
+    assert_eq!(Either::<Option<u8>, Option<char>>::distribute_none(None), Left(None));
+
+    let x : Either<Result<u8, &str>, Result<char, &str>> = Left(Ok(1));
+    assert_eq!(Either::distribute_err(x.factor_err()), x);
+    assert_eq!(Either::<Result<u8, &str>, Result<char, &str>>::distribute_err(Err("eof")), Left(Err("eof")));
+}